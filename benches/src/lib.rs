@@ -17,6 +17,9 @@
 /// Default number of runs for benchmarks
 pub const RUNS: u32 = 5;
 
+/// Organization of generated projects, e.g. `generated/500` is a project with 500 test contracts.
+pub const GENERATED_ORG: &str = "generated";
+
 /// Configuration for repositories to benchmark
 #[derive(Debug, Clone)]
 pub struct RepoConfig {
@@ -44,6 +47,18 @@ fn from_str(spec: &str) -> Result<Self> {
         let org = path_parts[0];
         let repo = path_parts[1];
 
+        if org == GENERATED_ORG {
+            let contracts: usize = repo.parse().wrap_err_with(|| {
+                format!("Invalid generated project '{spec}'. Expected 'generated/<contracts>'")
+            })?;
+            return Ok(Self {
+                name: format!("generated-{contracts}"),
+                org: org.to_string(),
+                repo: repo.to_string(),
+                rev: String::new(),
+            });
+        }
+
         // Try to find this repo in BENCHMARK_REPOS to get the full config
         let existing_config = BENCHMARK_REPOS.iter().find(|r| r.org == org && r.repo == repo);
 
@@ -70,6 +85,13 @@ fn from_str(spec: &str) -> Result<Self> {
     }
 }
 
+impl RepoConfig {
+    /// Returns the number of test contracts if this is a generated project.
+    pub fn generated_contracts(&self) -> Option<usize> {
+        if self.org == GENERATED_ORG { self.repo.parse().ok() } else { None }
+    }
+}
+
 /// Available repositories for benchmarking
 pub fn default_benchmark_repos() -> Vec<RepoConfig> {
     vec![
@@ -137,6 +159,12 @@ pub fn setup(config: &RepoConfig) -> Result<Self> {
             }
         }
 
+        if let Some(contracts) = config.generated_contracts() {
+            Self::generate_test_contracts(&root_path, contracts)?;
+            sh_println!("  ✅ Project {} generated at {}", config.name, root);
+            return Ok(Self { name: config.name.to_string(), root_path, temp_project });
+        }
+
         // Clone the repository
         let repo_url = format!("https://github.com/{}/{}.git", config.org, config.repo);
         clone_remote(&repo_url, root, true);
@@ -162,6 +190,59 @@ pub fn setup(config: &RepoConfig) -> Result<Self> {
         Ok(Self { name: config.name.to_string(), root_path, temp_project })
     }
 
+    /// Generate a project with the given number of test contracts.
+    ///
+    /// Every test contract gets its own backend, so this measures the per-contract setup cost of
+    /// `forge test` rather than the cost of the tests themselves.
+    fn generate_test_contracts(root: &Path, contracts: usize) -> Result<()> {
+        std::fs::create_dir_all(root.join("src"))?;
+        std::fs::create_dir_all(root.join("test"))?;
+        std::fs::write(
+            root.join("foundry.toml"),
+            "[profile.default]\nsrc = \"src\"\ntest = \"test\"\nout = \"out\"\nlibs = []\n",
+        )?;
+        std::fs::write(
+            root.join("src/Counter.sol"),
+            r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Counter {
+    uint256 public number;
+
+    function increment() public {
+        number++;
+    }
+}
+"#,
+        )?;
+        for i in 0..contracts {
+            std::fs::write(
+                root.join(format!("test/Counter{i}.t.sol")),
+                format!(
+                    r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import {{Counter}} from "../src/Counter.sol";
+
+contract Counter{i}Test {{
+    Counter counter;
+
+    function setUp() public {{
+        counter = new Counter();
+    }}
+
+    function test_increment() public {{
+        counter.increment();
+        require(counter.number() == 1);
+    }}
+}}
+"#
+                ),
+            )?;
+        }
+        Ok(())
+    }
+
     /// Install npm dependencies if package.json exists
     #[allow(unused_must_use)]
     fn install_npm_dependencies(root: &Path) -> Result<()> {
@@ -49,7 +49,8 @@ struct Cli {
     benchmarks: Option<Vec<String>>,
 
     /// Run only on specific repositories (comma-separated in org/repo[:rev] format:
-    /// ithacaxyz/account,Vectorized/solady:main,foundry-rs/foundry:v1.0.0)
+    /// ithacaxyz/account,Vectorized/solady:main,foundry-rs/foundry:v1.0.0), or on a generated
+    /// project with the given number of test contracts (generated/500)
     #[clap(long, value_delimiter = ',')]
     repos: Option<Vec<String>>,
 }
@@ -96,6 +96,14 @@ pub fn generate_markdown(&self, versions: &[String], repos: &[RepoConfig]) -> St
         // Repositories tested
         output.push_str("### Repositories Tested\n\n");
         for (i, repo) in repos.iter().enumerate() {
+            if let Some(contracts) = repo.generated_contracts() {
+                output.push_str(&format!(
+                    "{}. {} (generated, {contracts} test contracts)\n",
+                    i + 1,
+                    repo.name
+                ));
+                continue;
+            }
             output.push_str(&format!(
                 "{}. [{}/{}](https://github.com/{}/{})\n",
                 i + 1,
@@ -88,6 +88,10 @@ pub enum EthRequest {
     #[serde(rename = "eth_getAccountInfo")]
     EthGetAccountInfo(Address, Option<BlockId>),
 
+    /// Returns the account info with the code as stored, see [`EthRequest::EthGetRawCodeAt`]
+    #[serde(rename = "eth_getRawAccountInfo")]
+    EthGetRawAccountInfo(Address, Option<BlockId>),
+
     #[serde(rename = "eth_getStorageAt")]
     EthGetStorageAt(Address, U256, Option<BlockId>),
 
@@ -124,6 +128,11 @@ pub enum EthRequest {
     #[serde(rename = "eth_getCode")]
     EthGetCodeAt(Address, Option<BlockId>),
 
+    /// Returns the code as stored, keeping the OwnableAccount wrapper Fluent stores EVM contracts
+    /// in, where `eth_getCode` returns the EVM bytecode.
+    #[serde(rename = "eth_getRawCode")]
+    EthGetRawCodeAt(Address, Option<BlockId>),
+
     /// Returns the account and storage values of the specified account including the Merkle-proof.
     /// This call can be used to verify that the data you are pulling from is not tampered with.
     #[serde(rename = "eth_getProof")]
@@ -780,6 +789,19 @@ fn test_eth_get_proof() {
         let _req = serde_json::from_value::<EthRequest>(value).unwrap();
     }
 
+    #[test]
+    fn test_eth_get_raw_code() {
+        let s = r#"{"method":"eth_getRawCode","params":["0x7F0d15C7FAae65896648C8273B6d7E43f58Fa842","latest"]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let req = serde_json::from_value::<EthRequest>(value).unwrap();
+        assert!(matches!(req, EthRequest::EthGetRawCodeAt(..)));
+
+        let s = r#"{"method":"eth_getRawAccountInfo","params":["0x7F0d15C7FAae65896648C8273B6d7E43f58Fa842","latest"]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let req = serde_json::from_value::<EthRequest>(value).unwrap();
+        assert!(matches!(req, EthRequest::EthGetRawAccountInfo(..)));
+    }
+
     #[test]
     fn test_eth_chain_id() {
         let s = r#"{"method": "eth_chainId", "params":[]}"#;
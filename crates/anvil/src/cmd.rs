@@ -10,7 +10,7 @@
 use clap::Parser;
 use core::fmt;
 use foundry_common::shell;
-use foundry_config::{Chain, Config, FigmentProviders};
+use foundry_config::{Chain, Config, FigmentProviders, FluentGenesis};
 use foundry_evm::hardfork::{EthereumHardfork, OpHardfork};
 use foundry_evm_networks::NetworkConfigs;
 use futures::FutureExt;
@@ -279,6 +279,7 @@ pub fn into_node_config(self) -> eyre::Result<NodeConfig> {
             .with_transaction_block_keeper(self.transaction_block_keeper)
             .with_max_persisted_states(self.max_persisted_states)
             .with_networks(self.evm.networks)
+            .with_fluent_genesis(self.evm.fluent_genesis)
             .with_disable_default_create2_deployer(self.evm.disable_default_create2_deployer)
             .with_disable_pool_balance_checks(self.evm.disable_pool_balance_checks)
             .with_slots_in_an_epoch(self.slots_in_an_epoch)
@@ -606,6 +607,18 @@ pub struct AnvilEvmArgs {
 
     #[command(flatten)]
     pub networks: NetworkConfigs,
+
+    /// The Fluent genesis providing the system contracts if running a Fluent node.
+    ///
+    /// Either `strict`, enforcing the same limits as the chain, `permissive` or the path to a
+    /// genesis JSON file, optionally gzip compressed.
+    #[arg(
+        long,
+        help_heading = "Networks",
+        value_name = "GENESIS",
+        default_value_t = FluentGenesis::Strict
+    )]
+    pub fluent_genesis: FluentGenesis,
 }
 
 /// Resolves an alias passed as fork-url to the matching url defined in the rpc_endpoints section
@@ -906,6 +919,29 @@ fn can_parse_disable_code_size_limit() {
         assert!(args.is_err());
     }
 
+    #[test]
+    fn can_parse_fluent() {
+        let args: NodeArgs = NodeArgs::parse_from(["anvil", "--fluent"]);
+        let config = args.into_node_config().unwrap();
+        assert!(config.networks.is_fluent());
+        assert_eq!(config.fluent_genesis, FluentGenesis::Strict);
+
+        let args: NodeArgs = NodeArgs::parse_from([
+            "anvil",
+            "--chain-id",
+            "20994",
+            "--fluent-genesis",
+            "permissive",
+        ]);
+        let config = args.into_node_config().unwrap();
+        assert!(config.networks.is_fluent());
+        assert_eq!(config.fluent_genesis, FluentGenesis::Permissive);
+
+        let args: NodeArgs = NodeArgs::parse_from(["anvil"]);
+        let config = args.into_node_config().unwrap();
+        assert!(!config.networks.is_fluent());
+    }
+
     #[test]
     fn can_parse_host() {
         let args = NodeArgs::parse_from(["anvil"]);
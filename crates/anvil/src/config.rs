@@ -35,9 +35,9 @@
     ALCHEMY_FREE_TIER_CUPS, NON_ARCHIVE_NODE_WARNING, REQUEST_TIMEOUT,
     provider::{ProviderBuilder, RetryProvider},
 };
-use foundry_config::Config;
+use foundry_config::{Config, FluentGenesis};
 use foundry_evm::{
-    backend::{BlockchainDb, BlockchainDbMeta, SharedBackend},
+    backend::{BlockchainDb, BlockchainDbMeta, SharedBackend, load_fluent_genesis},
     constants::DEFAULT_CREATE2_DEPLOYER,
     core::AsEnvMut,
     hardfork::{
@@ -204,6 +204,8 @@ pub struct NodeConfig {
     pub precompile_factory: Option<Arc<dyn PrecompileFactory>>,
     /// Networks to enable features for.
     pub networks: NetworkConfigs,
+    /// The Fluent genesis with the system contracts, applied if running a Fluent node.
+    pub fluent_genesis: FluentGenesis,
     /// Do not print log messages.
     pub silent: bool,
     /// The path where states are cached.
@@ -499,6 +501,8 @@ fn default() -> Self {
             memory_limit: None,
             precompile_factory: None,
             networks: Default::default(),
+            // a local node should enforce the same limits as the chain
+            fluent_genesis: FluentGenesis::Strict,
             silent: false,
             cache_path: None,
         }
@@ -607,7 +611,7 @@ pub fn get_chain_id(&self) -> u64 {
     pub fn set_chain_id(&mut self, chain_id: Option<impl Into<u64>>) {
         self.chain_id = chain_id.map(Into::into);
         let chain_id = self.get_chain_id();
-        self.networks.with_chain_id(chain_id);
+        self.networks = self.networks.with_chain_id(chain_id);
         self.genesis_accounts.iter_mut().for_each(|wallet| {
             *wallet = wallet.clone().with_chain_id(Some(chain_id));
         });
@@ -1028,7 +1032,14 @@ pub fn with_precompile_factory(mut self, factory: impl PrecompileFactory + 'stat
     /// Enable features for provided networks.
     #[must_use]
     pub fn with_networks(mut self, networks: NetworkConfigs) -> Self {
-        self.networks = networks;
+        self.networks = networks.with_chain_id(self.get_chain_id());
+        self
+    }
+
+    /// Sets the Fluent genesis to apply if running a Fluent node.
+    #[must_use]
+    pub fn with_fluent_genesis(mut self, fluent_genesis: FluentGenesis) -> Self {
+        self.fluent_genesis = fluent_genesis;
         self
     }
 
@@ -1137,6 +1148,11 @@ pub(crate) async fn setup(&mut self) -> Result<mem::Backend> {
             balance: self.genesis_balance,
             accounts: self.genesis_accounts.iter().map(|acc| acc.address()).collect(),
             genesis_init: self.genesis.clone(),
+            fluent_genesis: self
+                .networks
+                .is_fluent()
+                .then(|| load_fluent_genesis(&self.fluent_genesis))
+                .transpose()?,
         };
 
         let mut decoder_builder = CallTraceDecoderBuilder::new();
@@ -1363,6 +1379,7 @@ pub async fn setup_fork_db_config(
 
             // need to update the dev signers and env with the chain id
             self.set_chain_id(Some(chain_id));
+            env.networks = self.networks;
             env.evm_env.cfg_env.chain_id = chain_id;
             env.tx.base.chain_id = chain_id.into();
             chain_id
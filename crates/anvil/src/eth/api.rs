@@ -9,7 +9,7 @@
         backend::{
             self,
             db::SerializableState,
-            mem::{MIN_CREATE_GAS, MIN_TRANSACTION_GAS},
+            mem::{MIN_CREATE_GAS, MIN_TRANSACTION_GAS, evm_compatible_code},
             notifications::NewBlockNotifications,
             validate::TransactionValidator,
         },
@@ -90,6 +90,7 @@
     database::CacheDB,
     interpreter::{InstructionResult, return_ok, return_revert},
     primitives::eip7702::PER_EMPTY_ACCOUNT_COST,
+    state::Bytecode,
 };
 use std::{sync::Arc, time::Duration};
 use tokio::{
@@ -178,6 +179,9 @@ pub async fn execute(&self, request: EthRequest) -> ResponseResult {
             EthRequest::EthGetAccountInfo(addr, block) => {
                 self.get_account_info(addr, block).await.to_rpc_result()
             }
+            EthRequest::EthGetRawAccountInfo(addr, block) => {
+                self.get_raw_account_info(addr, block).await.to_rpc_result()
+            }
             EthRequest::EthGetBalance(addr, block) => {
                 self.balance(addr, block).await.to_rpc_result()
             }
@@ -237,6 +241,9 @@ pub async fn execute(&self, request: EthRequest) -> ResponseResult {
             EthRequest::EthGetCodeAt(addr, block) => {
                 self.get_code(addr, block).await.to_rpc_result()
             }
+            EthRequest::EthGetRawCodeAt(addr, block) => {
+                self.get_raw_code(addr, block).await.to_rpc_result()
+            }
             EthRequest::EthGetProof(addr, keys, block) => {
                 self.get_proof(addr, keys, block).await.to_rpc_result()
             }
@@ -771,7 +778,27 @@ pub async fn get_account_info(
         block_number: Option<BlockId>,
     ) -> Result<alloy_rpc_types::eth::AccountInfo> {
         node_info!("eth_getAccountInfo");
+        self.account_info_at(address, block_number, false).await
+    }
 
+    /// Returns the account information with the code as stored, see [`Self::get_raw_code`]
+    ///
+    /// Handler for ETH RPC call: `eth_getRawAccountInfo`
+    pub async fn get_raw_account_info(
+        &self,
+        address: Address,
+        block_number: Option<BlockId>,
+    ) -> Result<alloy_rpc_types::eth::AccountInfo> {
+        node_info!("eth_getRawAccountInfo");
+        self.account_info_at(address, block_number, true).await
+    }
+
+    async fn account_info_at(
+        &self,
+        address: Address,
+        block_number: Option<BlockId>,
+        raw: bool,
+    ) -> Result<alloy_rpc_types::eth::AccountInfo> {
         if let Some(fork) = self.get_fork() {
             let block_request = self.block_request(block_number).await?;
             // check if the number predates the fork, if in fork mode
@@ -781,7 +808,7 @@ pub async fn get_account_info(
                     // if this predates the fork we need to fetch balance, nonce, code individually
                     // because the provider might not support this endpoint
                     let balance = fork.get_balance(address, number).map_err(BlockchainError::from);
-                    let code = fork.get_code(address, number).map_err(BlockchainError::from);
+                    let code = self.code_at(address, Some(number.into()), raw);
                     let nonce = self.get_transaction_count(address, Some(number.into()));
                     let (balance, code, nonce) = try_join!(balance, code, nonce)?;
 
@@ -790,7 +817,11 @@ pub async fn get_account_info(
                     // Anvil node is at the same block or higher than the fork block,
                     // return account info from backend to reflect current state.
                     let account_info = self.backend.get_account(address).await?;
-                    let code = self.backend.get_code(address, Some(block_request)).await?;
+                    let code = if raw {
+                        self.backend.get_raw_code(address, Some(block_request)).await?
+                    } else {
+                        self.backend.get_code(address, Some(block_request)).await?
+                    };
                     Ok(alloy_rpc_types::eth::AccountInfo {
                         balance: account_info.balance,
                         nonce: account_info.nonce,
@@ -801,7 +832,7 @@ pub async fn get_account_info(
         }
 
         let account = self.get_account(address, block_number);
-        let code = self.get_code(address, block_number);
+        let code = self.code_at(address, block_number, raw);
         let (account, code) = try_join!(account, code)?;
         Ok(alloy_rpc_types::eth::AccountInfo {
             balance: account.balance,
@@ -952,15 +983,48 @@ pub async fn block_uncles_count_by_number(&self, block_number: BlockNumber) -> R
     /// Handler for ETH RPC call: `eth_getCode`
     pub async fn get_code(&self, address: Address, block_number: Option<BlockId>) -> Result<Bytes> {
         node_info!("eth_getCode");
+        self.code_at(address, block_number, false).await
+    }
+
+    /// Returns the code at given address as stored, keeping the OwnableAccount wrapper Fluent
+    /// stores EVM contracts in.
+    ///
+    /// Handler for ETH RPC call: `eth_getRawCode`
+    pub async fn get_raw_code(
+        &self,
+        address: Address,
+        block_number: Option<BlockId>,
+    ) -> Result<Bytes> {
+        node_info!("eth_getRawCode");
+        self.code_at(address, block_number, true).await
+    }
+
+    async fn code_at(
+        &self,
+        address: Address,
+        block_number: Option<BlockId>,
+        raw: bool,
+    ) -> Result<Bytes> {
         let block_request = self.block_request(block_number).await?;
         // check if the number predates the fork, if in fork mode
         if let BlockRequest::Number(number) = block_request
             && let Some(fork) = self.get_fork()
             && fork.predates_fork(number)
         {
-            return Ok(fork.get_code(address, number).await?);
+            // the fork provider prefers the raw variant on Fluent endpoints
+            let code = fork.get_code(address, number).await?;
+            if raw {
+                return Ok(code);
+            }
+            return Ok(Bytecode::new_raw_checked(code.clone())
+                .map(evm_compatible_code)
+                .unwrap_or(code));
+        }
+        if raw {
+            self.backend.get_raw_code(address, Some(block_request)).await
+        } else {
+            self.backend.get_code(address, Some(block_request)).await
         }
-        self.backend.get_code(address, Some(block_request)).await
     }
 
     /// Returns the account and storage values of the specified account including the Merkle-proof.
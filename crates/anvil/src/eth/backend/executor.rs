@@ -35,17 +35,18 @@
 };
 use foundry_evm::{
     backend::DatabaseError,
-    core::{either_evm::EitherEvm, precompiles::EC_RECOVER},
+    core::{either_evm::EitherEvm, precompiles::EC_RECOVER, rwasm_evm::RwasmEthEvm},
     traces::{CallTraceDecoder, CallTraceNode},
 };
 use foundry_evm_networks::NetworkConfigs;
 use foundry_primitives::{FoundryReceiptEnvelope, FoundryTxEnvelope};
 use op_revm::{OpContext, OpTransaction};
 use revm::{
-    Database, Inspector,
-    context::{Block as RevmBlock, Cfg, TxEnv},
+    Database, Inspector, Journal,
+    context::{Block as RevmBlock, Cfg, JournalTr, LocalContext, TxEnv},
     context_interface::result::{EVMError, ExecutionResult, Output},
     interpreter::InstructionResult,
+    precompile::{PrecompileSpecId, Precompiles},
     primitives::hardfork::SpecId,
 };
 use std::{fmt::Debug, sync::Arc};
@@ -509,6 +510,25 @@ pub fn new_evm_with_inspector<DB, I>(
             env.evm_env.block_env.clone(),
         );
         EitherEvm::Op(OpEvmFactory::default().create_evm_with_inspector(db, evm_env, inspector))
+    } else if env.networks.is_fluent() {
+        let spec = env.evm_env.cfg_env.spec;
+        let ctx = EthEvmContext {
+            journaled_state: {
+                let mut journal = Journal::new(db);
+                journal.set_spec_id(spec);
+                journal
+            },
+            block: env.evm_env.block_env.clone(),
+            cfg: env.evm_env.cfg_env.clone(),
+            tx: env.tx.base.clone(),
+            chain: (),
+            local: LocalContext::default(),
+            error: Ok(()),
+        };
+        let precompiles =
+            PrecompilesMap::from_static(Precompiles::new(PrecompileSpecId::from_spec_id(spec)));
+
+        EitherEvm::Rwasm(RwasmEthEvm::new(ctx, inspector, precompiles, true))
     } else {
         let eth =
             EthEvmFactory::default().create_evm_with_inspector(db, env.evm_env.clone(), inspector);
@@ -3,8 +3,9 @@
 use crate::eth::backend::db::Db;
 use alloy_genesis::{Genesis, GenesisAccount};
 use alloy_primitives::{Address, U256};
-use foundry_evm::backend::DatabaseResult;
+use foundry_evm::backend::{DatabaseResult, GenesisState};
 use revm::{bytecode::Bytecode, primitives::KECCAK_EMPTY, state::AccountInfo};
+use std::{collections::BTreeMap, sync::Arc};
 use tokio::sync::RwLockWriteGuard;
 
 /// Genesis settings
@@ -20,6 +21,8 @@ pub struct GenesisConfig {
     pub accounts: Vec<Address>,
     /// The `genesis.json` if provided
     pub genesis_init: Option<Genesis>,
+    /// The Fluent genesis with the system contracts, if running a Fluent node
+    pub fluent_genesis: Option<Arc<GenesisState>>,
 }
 
 impl GenesisConfig {
@@ -39,19 +42,35 @@ pub fn account_infos(&self) -> impl Iterator<Item = (Address, AccountInfo)> + '_
     }
 
     /// If an initial `genesis.json` was provided, this applies the account alloc to the db
+    ///
+    /// The Fluent system contracts are applied first, so a `genesis.json` can override them. They
+    /// are skipped if `forked`, as a forked Fluent chain already has its system contracts.
     pub fn apply_genesis_json_alloc(
         &self,
         mut db: RwLockWriteGuard<'_, Box<dyn Db>>,
+        forked: bool,
     ) -> DatabaseResult<()> {
+        if !forked && let Some(ref fluent) = self.fluent_genesis {
+            self.apply_alloc(&mut db, &fluent.genesis.alloc)?;
+        }
         if let Some(ref genesis) = self.genesis_init {
-            for (addr, mut acc) in genesis.alloc.clone() {
-                let storage = std::mem::take(&mut acc.storage);
-                // insert all accounts
-                db.insert_account(addr, self.genesis_to_account_info(&acc));
-                // insert all storage values
-                for (k, v) in &storage.unwrap_or_default() {
-                    db.set_storage_at(addr, *k, *v)?;
-                }
+            self.apply_alloc(&mut db, &genesis.alloc)?;
+        }
+        Ok(())
+    }
+
+    /// Inserts all accounts of the alloc and their storage values into the db
+    fn apply_alloc(
+        &self,
+        db: &mut RwLockWriteGuard<'_, Box<dyn Db>>,
+        alloc: &BTreeMap<Address, GenesisAccount>,
+    ) -> DatabaseResult<()> {
+        for (addr, acc) in alloc {
+            // insert all accounts
+            db.insert_account(*addr, self.genesis_to_account_info(acc));
+            // insert all storage values
+            for (k, v) in acc.storage.iter().flatten() {
+                db.set_storage_at(*addr, *k, *v)?;
             }
         }
         Ok(())
@@ -59,10 +78,10 @@ pub fn apply_genesis_json_alloc(
 
     /// Converts a [`GenesisAccount`] to an [`AccountInfo`]
     fn genesis_to_account_info(&self, acc: &GenesisAccount) -> AccountInfo {
-        let GenesisAccount { code, balance, nonce, .. } = acc.clone();
-        let code = code.map(Bytecode::new_raw);
+        let GenesisAccount { code, balance, nonce, .. } = acc;
+        let code = code.clone().map(Bytecode::new_raw);
         AccountInfo {
-            balance,
+            balance: *balance,
             nonce: nonce.unwrap_or_default(),
             code_hash: code.as_ref().map(|code| code.hash_slow()).unwrap_or(KECCAK_EMPTY),
             code,
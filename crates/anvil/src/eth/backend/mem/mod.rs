@@ -89,6 +89,8 @@
 use chrono::Datelike;
 use eyre::{Context, Result};
 use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use fluentbase_evm::EthereumMetadata;
+use fluentbase_types::PRECOMPILE_EVM_RUNTIME;
 use foundry_evm::{
     backend::{DatabaseError, DatabaseResult, RevertStateSnapshotAction},
     constants::DEFAULT_CREATE2_DEPLOYER_RUNTIME_CODE,
@@ -120,7 +122,7 @@
     interpreter::InstructionResult,
     precompile::{PrecompileSpecId, Precompiles},
     primitives::{KECCAK_EMPTY, hardfork::SpecId},
-    state::AccountInfo,
+    state::{AccountInfo, Bytecode},
 };
 use std::{
     collections::BTreeMap,
@@ -405,7 +407,8 @@ pub(crate) fn set_executor(&self, executor_pk: String) -> Result<Address, Blockc
     async fn apply_genesis(&self) -> Result<(), DatabaseError> {
         trace!(target: "backend", "setting genesis balances");
 
-        if self.fork.read().is_some() {
+        let forked = self.fork.read().is_some();
+        if forked {
             // fetch all account first
             let mut genesis_accounts_futures = Vec::with_capacity(self.genesis.accounts.len());
             for address in self.genesis.accounts.iter().copied() {
@@ -442,7 +445,7 @@ async fn apply_genesis(&self) -> Result<(), DatabaseError> {
 
         let db = self.db.write().await;
         // apply the genesis.json alloc
-        self.genesis.apply_genesis_json_alloc(db)?;
+        self.genesis.apply_genesis_json_alloc(db, forked)?;
 
         trace!(target: "backend", "set genesis balances");
 
@@ -2532,23 +2535,59 @@ pub async fn get_code(
         self.with_database_at(block_request, |db, _| self.get_code_with_state(&db, address)).await?
     }
 
+    /// Returns the code of the address as stored, keeping the OwnableAccount wrapper of Fluent
+    /// contracts
+    ///
+    /// If the code is not present and fork mode is enabled then this will try to fetch it from the
+    /// forked client
+    pub async fn get_raw_code(
+        &self,
+        address: Address,
+        block_request: Option<BlockRequest>,
+    ) -> Result<Bytes, BlockchainError> {
+        self.with_database_at(block_request, |db, _| self.get_raw_code_with_state(&db, address))
+            .await?
+    }
+
+    /// Returns the EVM-compatible code of the address, see [`evm_compatible_code`]
     pub fn get_code_with_state(
         &self,
         state: &dyn DatabaseRef,
         address: Address,
     ) -> Result<Bytes, BlockchainError> {
         trace!(target: "backend", "get code for {:?}", address);
+        Ok(self.bytecode_with_state(state, address)?.map(evm_compatible_code).unwrap_or_default())
+    }
+
+    /// Returns the code of the address as stored
+    pub fn get_raw_code_with_state(
+        &self,
+        state: &dyn DatabaseRef,
+        address: Address,
+    ) -> Result<Bytes, BlockchainError> {
+        trace!(target: "backend", "get raw code for {:?}", address);
+        Ok(self
+            .bytecode_with_state(state, address)?
+            .map(|code| code.bytes()[..code.len()].to_vec().into())
+            .unwrap_or_default())
+    }
+
+    fn bytecode_with_state(
+        &self,
+        state: &dyn DatabaseRef,
+        address: Address,
+    ) -> Result<Option<Bytecode>, BlockchainError> {
         let account = state.basic_ref(address)?.unwrap_or_default();
         if account.code_hash == KECCAK_EMPTY {
             // if the code hash is `KECCAK_EMPTY`, we check no further
-            return Ok(Default::default());
+            return Ok(None);
         }
         let code = if let Some(code) = account.code {
             code
         } else {
             state.code_by_hash_ref(account.code_hash)?
         };
-        Ok(code.bytes()[..code.len()].to_vec().into())
+        Ok(Some(code))
     }
 
     /// Returns the balance of the address
@@ -3844,6 +3883,20 @@ pub fn prove_storage(storage: &HashMap<U256, U256>, keys: &[B256]) -> Vec<Vec<By
     proofs
 }
 
+/// Returns the EVM-compatible view of the code, as served by `eth_getCode` of Fluent nodes.
+///
+/// Fluent stores EVM contracts in an OwnableAccount wrapper owned by the EVM runtime, this
+/// returns the EVM bytecode stored in its metadata. Any other code is returned as is.
+pub fn evm_compatible_code(code: Bytecode) -> Bytes {
+    if let Bytecode::OwnableAccount(ref ownable) = code
+        && ownable.owner_address == PRECOMPILE_EVM_RUNTIME
+        && let Some(metadata) = EthereumMetadata::read_from_bytes(&ownable.metadata)
+    {
+        return metadata.code_copy();
+    }
+    code.bytes()[..code.len()].to_vec().into()
+}
+
 pub fn is_arbitrum(chain_id: u64) -> bool {
     if let Ok(chain) = NamedChain::try_from(chain_id) {
         return chain.is_arbitrum();
@@ -3860,7 +3913,43 @@ pub fn op_haltreason_to_instruction_result(op_reason: OpHaltReason) -> Instructi
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{NodeConfig, spawn};
+    use alloy_primitives::{address, bytes, keccak256};
+    use fluentbase_evm::AnalyzedBytecode;
+    use revm::bytecode::ownable_account::OwnableAccountBytecode;
+
+    #[tokio::test]
+    async fn test_raw_code_keeps_ownable_account() {
+        let (api, _handle) = spawn(NodeConfig::test()).await;
+
+        let evm_code = bytes!("0x6001600055");
+        let metadata = EthereumMetadata::Analyzed(AnalyzedBytecode::new(
+            evm_code.clone(),
+            keccak256(&evm_code),
+        ));
+        let ownable = Bytecode::OwnableAccount(OwnableAccountBytecode::new(
+            PRECOMPILE_EVM_RUNTIME,
+            metadata.write_to_bytes(),
+        ));
+        let raw_code: Bytes = ownable.bytes()[..ownable.len()].to_vec().into();
+
+        let contract = address!("0x2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599");
+        api.backend.set_code(contract, raw_code.clone()).await.unwrap();
+        assert_eq!(api.get_raw_code(contract, None).await.unwrap(), raw_code);
+        assert_eq!(api.get_code(contract, None).await.unwrap(), evm_code);
+
+        let info = api.get_raw_account_info(contract, None).await.unwrap();
+        assert_eq!(info.code, raw_code);
+        let info = api.get_account_info(contract, None).await.unwrap();
+        assert_eq!(info.code, evm_code);
+
+        // code that isn't wrapped is the same in both views
+        let legacy = address!("0x1000000000000000000000000000000000000001");
+        api.backend.set_code(legacy, evm_code.clone()).await.unwrap();
+        assert_eq!(api.get_raw_code(legacy, None).await.unwrap(), evm_code);
+        assert_eq!(api.get_code(legacy, None).await.unwrap(), evm_code);
+    }
 
     #[tokio::test]
     async fn test_deterministic_block_mining() {
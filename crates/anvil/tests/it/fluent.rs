@@ -0,0 +1,83 @@
+//! Tests for Fluent chain support.
+
+use alloy_network::{ReceiptResponse, TransactionBuilder};
+use alloy_primitives::{Address, Bytes, U256, bytes};
+use alloy_provider::Provider;
+use alloy_rpc_types::TransactionRequest;
+use alloy_serde::WithOtherFields;
+use alloy_sol_types::SolValue;
+use anvil::{NodeConfig, spawn};
+use foundry_common::provider::RetryProvider;
+use foundry_evm_networks::NetworkConfigs;
+
+// (module
+//   (type (func (param i32 i32)))
+//   (type (func))
+//   (import "fluentbase_v1preview" "_write" (func (type 0)))
+//   (func (type 1))
+//   (func (type 1) (call 0 (i32.const 0) (i32.const 32)))
+//   (memory 1)
+//   (export "deploy" (func 1))
+//   (export "main" (func 2))
+//   (export "memory" (memory 0))
+//   (data (i32.const 0) "\00...\2a"))
+const WASM_RETURN_42: Bytes = bytes!(
+    "0061736d0100000001090260027f7f00600000021f0114666c75656e74626173655f763170726576696577065f7772697465000003030201010503010001071a03066465706c6f790001046d61696e0002066d656d6f727902000a0d0202000b08004100412010000b0b26010041000b20000000000000000000000000000000000000000000000000000000000000002a"
+);
+
+// Deploys a contract which static calls the address in the first calldata word and returns the
+// result, reverting if the call fails.
+const EVM_FORWARDER: Bytes = bytes!(
+    "602080600b6000396000f36000356020600060006000845afa601557600080fd5b3d600060003e3d6000f3"
+);
+
+async fn deploy(provider: &RetryProvider, code: Bytes) -> Address {
+    let tx = TransactionRequest::default().with_deploy_code(code);
+    let receipt = provider
+        .send_transaction(WithOtherFields::new(tx))
+        .await
+        .unwrap()
+        .get_receipt()
+        .await
+        .unwrap();
+    assert!(receipt.status(), "{receipt:?}");
+    receipt.contract_address().unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_blended_call_to_wasm_contract() {
+    let (_api, handle) =
+        spawn(NodeConfig::test().with_networks(NetworkConfigs::with_fluent())).await;
+    let provider = handle.http_provider();
+
+    let wasm = deploy(&provider, WASM_RETURN_42).await;
+    let code = provider.get_code_at(wasm).await.unwrap();
+    assert!(!code.is_empty());
+    assert_ne!(code, WASM_RETURN_42, "the WASM module should be stored as rWasm");
+
+    let tx = TransactionRequest::default().with_to(wasm);
+    let output = provider.call(WithOtherFields::new(tx)).await.unwrap();
+    assert_eq!(U256::abi_decode(&output).unwrap(), U256::from(42));
+
+    // an EVM contract calling the WASM contract
+    let forwarder = deploy(&provider, EVM_FORWARDER).await;
+    let tx = TransactionRequest::default().with_to(forwarder).with_input(wasm.abi_encode());
+    let output = provider.call(WithOtherFields::new(tx)).await.unwrap();
+    assert_eq!(U256::abi_decode(&output).unwrap(), U256::from(42));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_wasm_contract_after_reset() {
+    let (api, handle) =
+        spawn(NodeConfig::test().with_networks(NetworkConfigs::with_fluent())).await;
+    let provider = handle.http_provider();
+
+    deploy(&provider, WASM_RETURN_42).await;
+    api.anvil_reset(None).await.unwrap();
+
+    // the system contracts are applied again with the genesis
+    let wasm = deploy(&provider, WASM_RETURN_42).await;
+    let tx = TransactionRequest::default().with_to(wasm);
+    let output = provider.call(WithOtherFields::new(tx)).await.unwrap();
+    assert_eq!(U256::abi_decode(&output).unwrap(), U256::from(42));
+}
@@ -5,6 +5,7 @@
 mod beacon_api;
 mod eip4844;
 mod eip7702;
+mod fluent;
 mod fork;
 mod gas;
 mod genesis;
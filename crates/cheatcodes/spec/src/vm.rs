@@ -573,6 +573,12 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function etch(address target, bytes calldata newRuntimeBytecode) external;
 
+    /// Sets an address' code to the rWasm module of a Rust contract, its deployed code on Fluent.
+    /// Takes in the package name of the contract, as in its `Cargo.toml` (`power-calculator`) or as
+    /// its artifact (`PowerCalculator.wasm`).
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function etchWasm(address target, string calldata packageName) external;
+
     /// Resets the nonce of an account to 0 for EOAs and 1 for contract accounts.
     #[cheatcode(group = Evm, safety = Unsafe)]
     function resetNonce(address account) external;
@@ -48,6 +48,9 @@ pub struct CheatsConfig {
     /// If Some, `vm.getDeployedCode` invocations are validated to be in scope of this list.
     /// If None, no validation is performed.
     pub available_artifacts: Option<ContractsByArtifact>,
+    /// All known artifacts, including the built Rust contracts the code cheatcodes resolve.
+    /// Unlike `available_artifacts`, these are kept if cheatcode artifacts are unchecked.
+    pub known_artifacts: Option<ContractsByArtifact>,
     /// Currently running artifact.
     pub running_artifact: Option<ArtifactId>,
     /// Whether to enable legacy (non-reverting) assertions.
@@ -69,6 +72,8 @@ pub fn new(
         let rpc_endpoints = config.rpc_endpoints.clone().resolved();
         trace!(?rpc_endpoints, "using resolved rpc endpoints");
 
+        // Rust contracts are resolved from the known artifacts even without safety checks
+        let known_artifacts = available_artifacts.clone();
         // If user explicitly disabled safety checks, do not set available_artifacts
         let available_artifacts =
             if config.unchecked_cheatcode_artifacts { None } else { available_artifacts };
@@ -88,6 +93,7 @@ pub fn new(
             evm_opts,
             labels: config.labels.clone(),
             available_artifacts,
+            known_artifacts,
             running_artifact,
             assertions_revert: config.assertions_revert,
             seed: config.fuzz.seed,
@@ -97,7 +103,7 @@ pub fn new(
 
     /// Returns a new `CheatsConfig` configured with the given `Config` and `EvmOpts`.
     pub fn clone_with(&self, config: &Config, evm_opts: EvmOpts) -> Self {
-        Self::new(config, evm_opts, self.available_artifacts.clone(), self.running_artifact.clone())
+        Self::new(config, evm_opts, self.known_artifacts.clone(), self.running_artifact.clone())
     }
 
     /// Attempts to canonicalize (see [std::fs::canonicalize]) the path.
@@ -218,6 +224,7 @@ fn default() -> Self {
             evm_opts: Default::default(),
             labels: Default::default(),
             available_artifacts: Default::default(),
+            known_artifacts: Default::default(),
             running_artifact: Default::default(),
             assertions_revert: true,
             seed: None,
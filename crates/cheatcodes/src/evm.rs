@@ -3,6 +3,7 @@
 use crate::{
     BroadcastableTransaction, Cheatcode, Cheatcodes, CheatcodesExecutor, CheatsCtxt, Error, Result,
     Vm::*,
+    fs::get_rust_artifact_code,
     inspector::{Ecx, RecordDebugStepInfo},
 };
 use alloy_consensus::TxEnvelope;
@@ -651,6 +652,21 @@ fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
     }
 }
 
+impl Cheatcode for etchWasmCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { target, packageName } = self;
+        ccx.ensure_not_precompile(target)?;
+        let code = get_rust_artifact_code(ccx.state, packageName, true)?
+            .ok_or_else(|| fmt_err!("no Rust contract matching `{packageName}` found"))?;
+        let (db, journal, _) = ccx.ecx.as_db_env_and_journal();
+        journal.load_account(db, *target)?;
+        let bytecode = Bytecode::new_raw_checked(code)
+            .map_err(|e| fmt_err!("failed to create bytecode: {e}"))?;
+        journal.set_code(*target, bytecode);
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for resetNonceCall {
     fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
         let Self { account } = self;
@@ -10,7 +10,9 @@
 use alloy_sol_types::SolValue;
 use dialoguer::{Input, Password};
 use forge_script_sequence::{BroadcastReader, TransactionWithMetadata};
-use foundry_common::fs;
+use foundry_common::{
+    fs, rust_artifact::is_rust_artifact, rust_contracts::find_package_name, wasm,
+};
 use foundry_config::fs_permissions::FsAccessKind;
 use revm::{
     context::{CreateScheme, JournalTr},
@@ -369,7 +371,12 @@ fn deploy_code(
     }
 
     if let Some(args) = constructor_args {
-        bytecode.extend_from_slice(args);
+        if wasm::is_wasm(&bytecode) {
+            // Wasm contracts read their constructor arguments from a custom section.
+            bytecode = wasm::with_constructor_args(&bytecode, args).to_vec();
+        } else {
+            bytecode.extend_from_slice(args);
+        }
     }
 
     let scheme =
@@ -411,6 +418,7 @@ fn deploy_code(
 /// - `path/to/contract.sol:0.8.23`
 /// - `ContractName`
 /// - `ContractName:0.8.23`
+/// - `PackageName.wasm` or `package-name`, a Rust contract, see [`get_rust_artifact_code`]
 ///
 /// This function is safe to use with contracts that have library dependencies.
 /// `alloy_json_abi::ContractObject` validates bytecode during JSON parsing and will
@@ -418,6 +426,9 @@ fn deploy_code(
 fn get_artifact_code(state: &Cheatcodes, path: &str, deployed: bool) -> Result<Bytes> {
     let path = if path.ends_with(".json") {
         PathBuf::from(path)
+    } else if path.ends_with(".wasm") {
+        return get_rust_artifact_code(state, path, deployed)?
+            .ok_or_else(|| fmt_err!("no Rust contract matching `{path}` found"));
     } else {
         let mut parts = path.split(':');
 
@@ -520,6 +531,13 @@ fn get_artifact_code(state: &Cheatcodes, path: &str, deployed: bool) -> Result<B
             }
         }
 
+        // Rust contracts can also be referenced by their package name alone.
+        if let (None, Some(name), None) = (&file, contract_name, &version)
+            && let Some(code) = get_rust_artifact_code(state, name, deployed)?
+        {
+            return Ok(code);
+        }
+
         // Fallback: construct path manually when no artifacts list or no match found
         let path_in_artifacts = match (file.map(|f| f.to_string_lossy().to_string()), contract_name)
         {
@@ -552,6 +570,33 @@ fn get_artifact_code(state: &Cheatcodes, path: &str, deployed: bool) -> Result<B
     maybe_bytecode.ok_or_else(|| fmt_err!("no bytecode for contract; is it abstract or unlinked?"))
 }
 
+/// Returns the wasm code or, if `deployed`, the rWasm code of the Rust contract `name`.
+///
+/// `name` is resolved among the known artifacts like
+/// [`RustContractsRegistry::get`](foundry_common::rust_contracts::RustContractsRegistry::get) does,
+/// e.g. `PowerCalculator.wasm` resolves to the `power-calculator` package. Returns `None` if no
+/// Rust contract matches.
+pub(crate) fn get_rust_artifact_code(
+    state: &Cheatcodes,
+    name: &str,
+    deployed: bool,
+) -> Result<Option<Bytes>> {
+    // Known artifacts include the built Rust contracts.
+    let Some(artifacts) = &state.config.known_artifacts else { return Ok(None) };
+    let rust_artifacts =
+        artifacts.iter().filter(|(id, _)| is_rust_artifact(id)).collect::<Vec<_>>();
+    let Some(package) =
+        find_package_name(name, rust_artifacts.iter().map(|(id, _)| id.name.as_str()))
+    else {
+        return Ok(None);
+    };
+    let (_, contract) = rust_artifacts.iter().find(|(id, _)| id.name == package).unwrap();
+    let code = if deployed { contract.deployed_bytecode() } else { contract.bytecode() };
+
+    let kind = if deployed { "rWasm" } else { "wasm" };
+    code.cloned().map(Some).ok_or_else(|| fmt_err!("no {kind} code for Rust contract `{name}`"))
+}
+
 impl Cheatcode for ffiCall {
     fn apply(&self, state: &mut Cheatcodes) -> Result {
         let Self { commandInput: input } = self;
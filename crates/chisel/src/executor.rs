@@ -205,7 +205,8 @@ async fn build_runner(&mut self, final_pc: usize) -> Result<ChiselRunner> {
             Some(backend) => backend,
             None => {
                 let fork = self.config.evm_opts.get_fork(&self.config.foundry_config, env.clone());
-                let backend = Backend::spawn(fork)?;
+                let genesis = self.config.evm_opts.load_genesis()?;
+                let backend = Backend::spawn_with_genesis(fork, genesis)?;
                 self.config.backend = Some(backend.clone());
                 backend
             }
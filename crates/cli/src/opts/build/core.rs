@@ -1,4 +1,4 @@
-use super::ProjectPathOpts;
+use super::{ProjectPathOpts, RustOpts};
 use crate::{opts::CompilerOpts, utils::LoadConfig};
 use clap::{Parser, ValueHint};
 use eyre::Result;
@@ -164,6 +164,9 @@ pub struct BuildOpts {
     #[arg(long, help_heading = "Disable reproducible build for rust contracts")]
     #[serde(skip)]
     pub no_docker: bool,
+
+    #[command(flatten)]
+    pub rust: RustOpts,
 }
 
 impl BuildOpts {
@@ -9,6 +9,9 @@
 mod paths;
 pub use self::paths::ProjectPathOpts;
 
+mod rust;
+pub use self::rust::RustOpts;
+
 mod utils;
 pub use self::utils::*;
 
@@ -0,0 +1,61 @@
+use clap::Parser;
+use foundry_config::RustArtifactKind;
+use serde::Serialize;
+
+/// Rust contract build options that can be set via command line arguments, which are intended to
+/// be merged into the `[rust]` section of an existing `foundry_config::Config`.
+///
+/// Per-package overrides in `foundry.toml` take precedence over these.
+#[derive(Clone, Debug, Default, Serialize, Parser)]
+#[command(next_help_heading = "Rust contract options")]
+pub struct RustOpts {
+    /// Number of rust contracts to build in parallel.
+    ///
+    /// Defaults to the number of logical cores.
+    #[arg(long = "rust-jobs", value_name = "JOBS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jobs: Option<usize>,
+
+    /// Build every rust contract both natively and with Docker and fail if the rWasm output
+    /// differs.
+    #[arg(long)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub check_reproducible: bool,
+
+    /// Cargo features to activate.
+    #[arg(long, num_args(1..), value_delimiter = ',', value_name = "FEATURES")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
+
+    /// Do not activate the default features of the crates.
+    #[arg(long)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_default_features: bool,
+
+    /// Optimize the wasm modules with `wasm-opt`.
+    #[arg(long)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub wasm_opt: bool,
+
+    /// Require `Cargo.lock` to be up to date.
+    #[arg(long)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub locked: bool,
+
+    /// The Rust toolchain to build with.
+    #[arg(long, value_name = "TOOLCHAIN")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toolchain: Option<String>,
+
+    /// The tag of the fluentbase build image.
+    ///
+    /// Defaults to the tag matching the SDK version of each crate.
+    #[arg(long, value_name = "TAG")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docker_tag: Option<String>,
+
+    /// The artifacts to generate for rust contracts.
+    #[arg(long = "rust-artifacts", num_args(1..), value_delimiter = ',', value_name = "ARTIFACT")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<RustArtifactKind>,
+}
@@ -3,7 +3,7 @@
 use alloy_primitives::{Address, B256, U256};
 use clap::Parser;
 use foundry_config::{
-    Chain, Config,
+    Chain, Config, FluentGenesis,
     figment::{
         self, Metadata, Profile, Provider,
         error::Kind::InvalidType,
@@ -103,6 +103,14 @@ pub struct EvmArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub create2_deployer: Option<Address>,
 
+    /// The Fluent genesis providing the system contracts and runtime precompiles of the backend.
+    ///
+    /// Either `permissive` (default, without the EIP-170 contract size limit), `strict` or the
+    /// path to a genesis JSON file, optionally gzip compressed.
+    #[arg(long, value_name = "GENESIS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fluent_genesis: Option<FluentGenesis>,
+
     /// Sets the number of assumed available compute units per second for this provider
     ///
     /// default value: 330
@@ -332,4 +340,15 @@ fn test_chain_id() {
         let config = Config::from_provider(Config::figment().merge(args)).unwrap();
         assert_eq!(config.chain, Some(Chain::mainnet()));
     }
+
+    #[test]
+    fn can_parse_fluent_genesis() {
+        let args = EvmArgs::parse_from(["foundry-cli", "--fluent-genesis", "strict"]);
+        assert_eq!(args.fluent_genesis, Some(FluentGenesis::Strict));
+        let config = Config::from_provider(Config::figment().merge(args)).unwrap();
+        assert_eq!(config.fluent_genesis, FluentGenesis::Strict);
+
+        let args = EvmArgs::parse_from(["foundry-cli", "--fluent-genesis", "genesis.json"]);
+        assert_eq!(args.fluent_genesis, Some(FluentGenesis::Path("genesis.json".into())));
+    }
 }
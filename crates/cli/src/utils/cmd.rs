@@ -202,6 +202,9 @@ fn load_config_and_evm_opts_no_warnings(&self) -> Result<(Config, EvmOpts)> {
             evm_opts.fork_url = Some(fork_url?.into_owned());
         }
 
+        // use the genesis path relative to the project root
+        evm_opts.fluent_genesis = config.fluent_genesis.clone();
+
         // If no fork is used, we should use genesis configuration
         if evm_opts.fork_url.is_none() {
             // TODO(d1r1): add custom genesis processing (not working for now)
@@ -3,12 +3,18 @@
     preprocessor::DynamicTestLinkingPreprocessor, shell, term::SpinnerReporter, TestFunctionExt,
 };
 use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN, Cell, Color, Table};
-use eyre::{Result, WrapErr};
-use fluentbase_build::{execute_build, Artifact as FluentArtifact, BuildArgs, DEFAULT_DOCKER_TAG};
+use eyre::Result;
 use foundry_block_explorers::contract::Metadata;
-use foundry_common::rust_contracts::RustContractsRegistry;
+use foundry_common::{
+    rust_compiler::{
+        crate_sources, RustWasmCompiler, RustWasmLanguage, RustWasmSettings,
+        RUST_BUILD_CACHE_FILENAME, RUST_FILES_CACHE_FILENAME,
+    },
+    rust_contracts::{RustContractsFilter, RustContractsRegistry},
+    wasm::{RWASM_MAX_CODE_SIZE, WASM_MAX_CODE_SIZE},
+};
 use foundry_compilers::{
-    artifacts::{remappings::Remapping, BytecodeObject, Contract, Source},
+    artifacts::{remappings::Remapping, BytecodeObject, Contract, Source, Sources},
     compilers::{
         solc::{Solc, SolcCompiler},
         Compiler,
@@ -20,6 +26,7 @@
     solc::SolcSettings,
     Artifact, Project, ProjectBuilder, ProjectCompileOutput, ProjectPathsConfig, SolcConfig,
 };
+use foundry_config::Config;
 use num_format::{Locale, ToFormattedString};
 use std::{
     collections::BTreeMap,
@@ -34,6 +41,9 @@
 /// A Solar compiler instance, to grant syntactic and semantic analysis capabilities.
 pub type Analysis = Arc<solar::sema::Compiler>;
 
+/// The project building the Rust contracts, and the sources to build.
+type RustBuild = (Project<RustWasmCompiler>, Sources);
+
 /// Builder type to configure how to compile a project.
 ///
 /// This is merely a wrapper for [`Project::compile()`] which also prints to stdout depending on its
@@ -64,8 +74,8 @@ pub struct ProjectCompiler {
     /// Whether to compile with dynamic linking tests and scripts.
     dynamic_test_linking: bool,
 
-    /// Whether to use docker for the reproducible build
-    no_docker: bool,
+    /// The config the Rust contracts are built with, loaded from the project root if not set.
+    config: Option<Config>,
 }
 
 impl Default for ProjectCompiler {
@@ -88,8 +98,7 @@ pub fn new() -> Self {
             ignore_eip_3860: false,
             files: Vec::new(),
             dynamic_test_linking: false,
-            // docker enabled by default
-            no_docker: false,
+            config: None,
         }
     }
 
@@ -143,27 +152,51 @@ pub fn dynamic_test_linking(mut self, preprocess: bool) -> Self {
         self
     }
 
-    /// Sets no-docker flag
+    /// Sets the config the Rust contracts are built with: the `[rust]` section, which selects the
+    /// contracts and their build settings, and `no_docker`.
+    ///
+    /// Defaults to the config of the project root, which doesn't include command line overrides.
     #[inline]
-    pub fn no_docker(mut self, yes: bool) -> Self {
-        self.no_docker = yes;
+    pub fn config(mut self, config: &Config) -> Self {
+        self.config = Some(config.clone());
         self
     }
 
-    // TODO(d1r1): move rust compilation to the foundry-compilers crate.
-    //
     /// Compiles the project.
-    #[instrument(target = "forge::compile", skip_all)]
+    ///
+    /// Rust contracts found in the source directory are built with the [`RustWasmCompiler`],
+    /// concurrently with the Solidity sources. Use [`Self::compile_with_rust`] to get their
+    /// output.
     pub fn compile<C: Compiler<CompilerContract = Contract>>(
-        mut self,
+        self,
         project: &Project<C>,
     ) -> Result<ProjectCompileOutput<C>>
+    where
+        DynamicTestLinkingPreprocessor: Preprocessor<C>,
+    {
+        self.compile_with_rust(project).map(|(output, _)| output)
+    }
+
+    /// Compiles the project, also returning the output of the Rust contracts build if the project
+    /// has any.
+    ///
+    /// See [`compiled_rust_artifacts`](crate::rust_artifact::compiled_rust_artifacts) to add the
+    /// built Rust contracts to the known contracts.
+    #[instrument(target = "forge::compile", skip_all)]
+    pub fn compile_with_rust<C: Compiler<CompilerContract = Contract>>(
+        mut self,
+        project: &Project<C>,
+    ) -> Result<(ProjectCompileOutput<C>, Option<ProjectCompileOutput<RustWasmCompiler>>)>
     where
         DynamicTestLinkingPreprocessor: Preprocessor<C>,
     {
         self.project_root = project.root().to_path_buf();
 
-        let rust_contracts = self.build_rust_contracts(project)?;
+        let config = match self.config.take() {
+            Some(config) => config,
+            None => Config::load_with_root(project.root())?,
+        };
+        let rust_build = self.prepare_rust_build(project, &config)?;
 
         // TODO: Avoid using std::process::exit(0).
         // Replacing this with a return (e.g., Ok(ProjectCompileOutput::default())) would be more
@@ -171,7 +204,7 @@ pub fn compile<C: Compiler<CompilerContract = Contract>>(
         // breaks compatibility with downstream crates like `foundry-cli`. This would need a
         // broader refactor across the call chain. Leaving it as-is for now until a larger
         // refactor is feasible.
-        if !project.paths.has_input_files() && self.files.is_empty() && rust_contracts == 0 {
+        if !project.paths.has_input_files() && self.files.is_empty() && rust_build.is_none() {
             sh_println!("Nothing to compile")?;
             std::process::exit(0);
         }
@@ -180,7 +213,7 @@ pub fn compile<C: Compiler<CompilerContract = Contract>>(
         let files = std::mem::take(&mut self.files);
         let preprocess = self.dynamic_test_linking;
 
-        self.compile_with(|| {
+        self.compile_with(rust_build, || {
             let sources = if !files.is_empty() {
                 Source::read_all(files)?
             } else {
@@ -197,138 +230,121 @@ pub fn compile<C: Compiler<CompilerContract = Contract>>(
     }
 
     /// Compiles the project with the given closure
+    ///
+    /// If given, the Rust contracts are built on a separate thread while `f` runs.
     fn compile_with<C: Compiler<CompilerContract = Contract>, F>(
         self,
+        rust_build: Option<RustBuild>,
         f: F,
-    ) -> Result<ProjectCompileOutput<C>>
+    ) -> Result<(ProjectCompileOutput<C>, Option<ProjectCompileOutput<RustWasmCompiler>>)>
     where
         F: FnOnce() -> Result<ProjectCompileOutput<C>>,
     {
         let quiet = self.quiet.unwrap_or(false);
         let bail = self.bail.unwrap_or(true);
 
-        let output = with_compilation_reporter(quiet, Some(self.project_root.clone()), || {
-            tracing::debug!("compiling project");
-
-            let timer = Instant::now();
-            let r = f();
-            let elapsed = timer.elapsed();
-
-            tracing::debug!("finished compiling in {:.3}s", elapsed.as_secs_f64());
-            r
-        })?;
+        let (output, rust_output) =
+            with_compilation_reporter(quiet, Some(self.project_root.clone()), || {
+                tracing::debug!("compiling project");
+
+                let timer = Instant::now();
+                let (r, rust_r) = std::thread::scope(|s| {
+                    let rust = rust_build.map(|(project, sources)| {
+                        s.spawn(move || {
+                            foundry_compilers::project::ProjectCompiler::with_sources(
+                                &project, sources,
+                            )?
+                            .compile()
+                        })
+                    });
+                    let r = f();
+                    let rust_r = rust.map(|handle| {
+                        handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                    });
+                    (r, rust_r)
+                });
+                let elapsed = timer.elapsed();
+
+                tracing::debug!("finished compiling in {:.3}s", elapsed.as_secs_f64());
+                (r, rust_r)
+            });
+        let output = output?;
+        let rust_output = rust_output.transpose()?;
 
         if bail && output.has_compiler_errors() {
             eyre::bail!("{output}")
         }
+        if bail
+            && let Some(rust_output) = &rust_output
+            && rust_output.has_compiler_errors()
+        {
+            eyre::bail!("{rust_output}")
+        }
 
         if !quiet {
             if !shell::is_json() {
-                if output.is_unchanged() {
+                let rust_unchanged = rust_output.as_ref().is_none_or(|o| o.is_unchanged());
+                if output.is_unchanged() && rust_unchanged {
                     sh_println!("No files changed, compilation skipped")?;
                 } else {
                     // print the compiler output / warnings
-                    sh_println!("{output}")?;
+                    if !output.is_unchanged() {
+                        sh_println!("{output}")?;
+                    }
+                    if let Some(rust_output) = rust_output.as_ref().filter(|_| !rust_unchanged) {
+                        sh_println!("{rust_output}")?;
+                    }
                 }
             }
 
-            self.handle_output(&output)?;
+            self.handle_output(&output, rust_output.as_ref())?;
         }
 
-        Ok(output)
+        Ok((output, rust_output))
     }
 
-    /// Finds and compiles Rust contracts, returning the number of compiled contracts.
-    fn build_rust_contracts<C: Compiler<CompilerContract = Contract>>(
+    /// Finds the Rust contracts of the project and sets up the [`RustWasmCompiler`] project
+    /// building them.
+    ///
+    /// Returns `None` if the project doesn't contain any Rust contracts.
+    fn prepare_rust_build<C: Compiler<CompilerContract = Contract>>(
         &mut self,
         project: &Project<C>,
-    ) -> Result<usize> {
+        config: &Config,
+    ) -> Result<Option<RustBuild>> {
         // Find all Rust projects (crates) in source directories
-        let rust_registry =
-            RustContractsRegistry::new(&project.paths.sources, Some(project.root()))?;
+        let rust_registry = RustContractsRegistry::with_filter(
+            &project.paths.sources,
+            Some(project.root()),
+            &RustContractsFilter::from_config(config)?,
+        )?;
 
         if rust_registry.is_empty() {
-            return Ok(0);
-        }
-
-        sh_println!("Compiling {} Rust contract(s)...", rust_registry.len())?;
-        let contracts_count = rust_registry.len();
-        let timer = Instant::now();
-
-        // Determine which artifacts to generate
-        let generate_artifacts = vec![
-            FluentArtifact::Solidity,
-            FluentArtifact::Abi,
-            FluentArtifact::Foundry,
-            FluentArtifact::Rwasm,
-            FluentArtifact::Metadata,
-        ];
-
-        // Iterate through each found Rust project and compile it
-        for (package_name, info) in rust_registry.iter() {
-            // Get the directory name for logging
-            let project_dir_name =
-                info.path.file_name().and_then(|n| n.to_str()).unwrap_or(package_name);
-
-            // Artifact name is always: {package_name}.wasm
-            let artifact_name = info.artifact_name();
-
-            sh_println!(
-                "  - Compiling {} (package: {}, project-dir: {})...",
-                project_dir_name,
-                package_name,
-                info.path.display()
-            )?;
-
-            let mut sdk_version_or_docker_tag =
-                info.sdk_version.clone().unwrap_or_else(|| DEFAULT_DOCKER_TAG.to_string());
-            if !sdk_version_or_docker_tag.starts_with("v") {
-                sdk_version_or_docker_tag = format!("v{}", sdk_version_or_docker_tag);
-            }
-
-            // Configure the build
-            let build_args = BuildArgs {
-                contract_name: Some(artifact_name),
-                generate: generate_artifacts.clone(),
-                docker: !self.no_docker,
-                docker_tag: sdk_version_or_docker_tag,
-                mount_dir: Some(project.root().to_path_buf()),
-                output_path: Some(format!(
-                    "{}/{{contract_name}}",
-                    project.artifacts_path().display()
-                )),
-                wasm_opt: false,
-                locked: false,
-                rust_version: Some("1.92.0-x86_64-unknown-linux-gnu".to_string()),
-                ..Default::default()
-            };
-
-            sh_println!("  - Build args: {build_args:?}")?;
-
-            // Execute Rust contract build
-            execute_build(&build_args, Some(info.path.clone()))
-                .map_err(|e| eyre::eyre!("Build failed: {}", e))
-                .wrap_err_with(|| {
-                    format!(
-                        "Failed to build Rust contract '{}' at {}",
-                        package_name,
-                        info.path.display()
-                    )
-                })?;
-
-            // Remove the directory from file tracking after processing
-            self.files.retain(|file| !file.starts_with(&info.path));
+            return Ok(None);
         }
 
-        sh_println!("Finished compiling Rust contracts in {:.2?}", timer.elapsed())?;
-
-        Ok(contracts_count)
+        // Rust crates are built separately, don't pass them on to the Solidity pipeline
+        self.files
+            .retain(|file| !rust_registry.iter().any(|(_, info)| file.starts_with(&info.path)));
+
+        let mut rust_project = rust_project(project, RustWasmSettings::from_config(config))?;
+        rust_project.compiler = rust_project
+            .compiler
+            .with_jobs(config.rust.jobs)
+            .with_check_reproducible(config.rust.check_reproducible)
+            .with_quiet(self.quiet.unwrap_or(false) || shell::is_json());
+        let sources =
+            Source::read_all(rust_registry.iter().flat_map(|(_, info)| crate_sources(&info.path)))?;
+        tracing::debug!("found {} rust contract(s)", rust_registry.len());
+
+        Ok(Some((rust_project, sources)))
     }
 
     /// If configured, this will print sizes or names
     fn handle_output<C: Compiler<CompilerContract = Contract>>(
         &self,
         output: &ProjectCompileOutput<C>,
+        rust_output: Option<&ProjectCompileOutput<RustWasmCompiler>>,
     ) -> Result<()> {
         let print_names = self.print_names.unwrap_or(false);
         let print_sizes = self.print_sizes.unwrap_or(false);
@@ -336,7 +352,8 @@ fn handle_output<C: Compiler<CompilerContract = Contract>>(
         // print any sizes or names
         if print_names {
             let mut artifacts: BTreeMap<_, Vec<_>> = BTreeMap::new();
-            for (name, (_, version)) in output.versioned_artifacts() {
+            let rust_artifacts = rust_output.iter().flat_map(|output| output.versioned_artifacts());
+            for (name, (_, version)) in output.versioned_artifacts().chain(rust_artifacts) {
                 artifacts.entry(version).or_default().push(name);
             }
 
@@ -363,7 +380,8 @@ fn handle_output<C: Compiler<CompilerContract = Contract>>(
                 sh_println!()?;
             }
 
-            let mut size_report = SizeReport { contracts: BTreeMap::new() };
+            let mut size_report =
+                SizeReport { contracts: BTreeMap::new(), rust_contracts: BTreeMap::new() };
 
             let mut artifacts: BTreeMap<String, Vec<_>> = BTreeMap::new();
             for (id, artifact) in output.artifact_ids().filter(|(id, _)| {
@@ -406,6 +424,17 @@ fn handle_output<C: Compiler<CompilerContract = Contract>>(
                 }
             }
 
+            // Rust contracts are deployed as wasm and stored as rWasm, with their own limits
+            for (id, artifact) in rust_output.iter().flat_map(|output| output.artifact_ids()) {
+                size_report.rust_contracts.insert(
+                    id.name,
+                    RustContractInfo {
+                        wasm_size: contract_size(artifact, true).unwrap_or_default(),
+                        rwasm_size: contract_size(artifact, false).filter(|size| *size > 0),
+                    },
+                );
+            }
+
             sh_println!("{size_report}")?;
 
             eyre::ensure!(
@@ -419,6 +448,16 @@ fn handle_output<C: Compiler<CompilerContract = Contract>>(
                 "some contracts exceed the initcode size limit \
                  (EIP-3860: {CONTRACT_INITCODE_SIZE_LIMIT} bytes)"
             );
+            eyre::ensure!(
+                !size_report.exceeds_wasm_size_limit(),
+                "some Rust contracts exceed the wasm size limit ({WASM_MAX_CODE_SIZE} bytes)"
+            );
+            // Fluent doesn't define an rWasm limit yet, see `RWASM_MAX_CODE_SIZE`
+            if size_report.exceeds_rwasm_size_limit() {
+                sh_warn!(
+                    "some Rust contracts exceed the rWasm size limit ({RWASM_MAX_CODE_SIZE} bytes)"
+                )?;
+            }
         }
 
         Ok(())
@@ -435,6 +474,8 @@ fn handle_output<C: Compiler<CompilerContract = Contract>>(
 pub struct SizeReport {
     /// `contract name -> info`
     pub contracts: BTreeMap<String, ContractInfo>,
+    /// `package name -> info` of Rust contracts
+    pub rust_contracts: BTreeMap<String, RustContractInfo>,
 }
 
 impl SizeReport {
@@ -467,6 +508,16 @@ pub fn exceeds_runtime_size_limit(&self) -> bool {
     pub fn exceeds_initcode_size_limit(&self) -> bool {
         self.max_init_size() > CONTRACT_INITCODE_SIZE_LIMIT
     }
+
+    /// Returns true if any Rust contract exceeds the wasm size limit.
+    pub fn exceeds_wasm_size_limit(&self) -> bool {
+        self.rust_contracts.values().any(|c| c.wasm_size > WASM_MAX_CODE_SIZE)
+    }
+
+    /// Returns true if any Rust contract exceeds the rWasm size limit.
+    pub fn exceeds_rwasm_size_limit(&self) -> bool {
+        self.rust_contracts.values().any(|c| c.rwasm_size.unwrap_or(0) > RWASM_MAX_CODE_SIZE)
+    }
 }
 
 impl Display for SizeReport {
@@ -475,6 +526,9 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
             writeln!(f, "{}", self.format_json_output())?;
         } else {
             writeln!(f, "\n{}", self.format_table_output())?;
+            if !self.rust_contracts.is_empty() {
+                writeln!(f, "\n{}", self.format_rust_table_output())?;
+            }
         }
         Ok(())
     }
@@ -497,6 +551,18 @@ fn format_json_output(&self) -> String {
                     }),
                     )
                 })
+                .chain(self.rust_contracts.iter().map(|(name, contract)| {
+                    (
+                        name.clone(),
+                        serde_json::json!({
+                            "wasm_size": contract.wasm_size,
+                            "rwasm_size": contract.rwasm_size,
+                            "init_size": contract.wasm_size,
+                            "wasm_margin": WASM_MAX_CODE_SIZE as isize - contract.wasm_size as isize,
+                            "rwasm_margin": contract.rwasm_size.map(|size| RWASM_MAX_CODE_SIZE as isize - size as isize),
+                        }),
+                    )
+                }))
                 .collect::<serde_json::Map<_, _>>();
 
         serde_json::to_string(&contracts).unwrap()
@@ -552,6 +618,58 @@ fn format_table_output(&self) -> Table {
 
         table
     }
+
+    fn format_rust_table_output(&self) -> Table {
+        let mut table = Table::new();
+        if shell::is_markdown() {
+            table.load_preset(ASCII_MARKDOWN);
+        } else {
+            table.apply_modifier(UTF8_ROUND_CORNERS);
+        }
+
+        table.set_header(vec![
+            Cell::new("Rust Contract"),
+            Cell::new("Wasm (Initcode) Size (B)"),
+            Cell::new("rWasm Size (B)"),
+            Cell::new("Wasm Margin (B)"),
+            Cell::new("rWasm Margin (B)"),
+        ]);
+
+        let locale = &Locale::en;
+        for (name, contract) in &self.rust_contracts {
+            let wasm_margin = WASM_MAX_CODE_SIZE as isize - contract.wasm_size as isize;
+            let wasm_color = size_color(contract.wasm_size, WASM_MAX_CODE_SIZE);
+            let (rwasm_size, rwasm_margin, rwasm_color) = match contract.rwasm_size {
+                Some(size) => (
+                    size.to_formatted_string(locale),
+                    (RWASM_MAX_CODE_SIZE as isize - size as isize).to_formatted_string(locale),
+                    size_color(size, RWASM_MAX_CODE_SIZE),
+                ),
+                None => ("-".to_string(), "-".to_string(), Color::Reset),
+            };
+
+            table.add_row([
+                Cell::new(name),
+                Cell::new(contract.wasm_size.to_formatted_string(locale)).fg(wasm_color),
+                Cell::new(rwasm_size).fg(rwasm_color),
+                Cell::new(wasm_margin.to_formatted_string(locale)).fg(wasm_color),
+                Cell::new(rwasm_margin).fg(rwasm_color),
+            ]);
+        }
+
+        table
+    }
+}
+
+/// Returns the color of a size cell, yellow above 75% of the limit and red above the limit.
+fn size_color(size: usize, limit: usize) -> Color {
+    if size > limit {
+        Color::Red
+    } else if size > limit / 4 * 3 {
+        Color::Yellow
+    } else {
+        Color::Reset
+    }
 }
 
 /// Returns the deployed or init size of the contract.
@@ -590,6 +708,15 @@ pub struct ContractInfo {
     pub is_dev_contract: bool,
 }
 
+/// Size of a Rust contract, checked against Fluent's limits instead of the EVM ones.
+#[derive(Clone, Copy, Debug)]
+pub struct RustContractInfo {
+    /// Size of the wasm module in bytes, deployed as initcode
+    pub wasm_size: usize,
+    /// Size of the rWasm module in bytes, stored as the runtime code, if it was generated
+    pub rwasm_size: Option<usize>,
+}
+
 /// Compiles target file path.
 ///
 /// If `quiet` no solc related output will be emitted to stdout.
@@ -606,6 +733,31 @@ pub fn compile_target<C: Compiler<CompilerContract = Contract>>(
     ProjectCompiler::new().quiet(quiet).files([target_path.into()]).compile(project)
 }
 
+/// Creates the [Project] used to build the Rust contracts of `project`.
+///
+/// It shares paths, artifacts settings and diagnostic filters with `project`, but keeps its own
+/// cache files next to the Solidity one.
+pub fn rust_project<C: Compiler>(
+    project: &Project<C>,
+    settings: RustWasmSettings,
+) -> Result<Project<RustWasmCompiler>> {
+    let mut paths = project.paths.clone().with_language::<RustWasmLanguage>();
+    paths.cache = paths.cache.with_file_name(RUST_FILES_CACHE_FILENAME);
+
+    let mut compiler = RustWasmCompiler::new(project.root(), project.artifacts_path());
+    if project.cached {
+        compiler = compiler.with_build_cache(paths.cache.with_file_name(RUST_BUILD_CACHE_FILENAME));
+    }
+    Ok(ProjectBuilder::<RustWasmCompiler>::new(project.artifacts.clone())
+        .paths(paths)
+        .settings(settings)
+        .ignore_error_codes(project.ignored_error_codes.clone())
+        .ignore_paths(project.ignored_file_paths.clone())
+        .set_compiler_severity_filter(project.compiler_severity_filter)
+        .set_cached(project.cached)
+        .build(compiler)?)
+}
+
 /// Creates a [Project] from an Etherscan source.
 pub fn etherscan_project(metadata: &Metadata, target_path: &Path) -> Result<Project> {
     let target_path = dunce::canonicalize(target_path)?;
@@ -1,6 +1,9 @@
 //! Commonly used contract types and functions.
 
-use crate::{compile::PathOrContractInfo, find_metadata_start, strip_bytecode_placeholders};
+use crate::{
+    compile::PathOrContractInfo, find_metadata_start, rust_artifact::is_rust_artifact,
+    strip_bytecode_placeholders, wasm,
+};
 use alloy_dyn_abi::JsonAbiExt;
 use alloy_json_abi::{Event, Function, JsonAbi};
 use alloy_primitives::{Address, B256, Bytes, Selector, hex};
@@ -15,6 +18,7 @@
     utils::canonicalized,
 };
 use std::{
+    borrow::Cow,
     collections::BTreeMap,
     ops::Deref,
     path::{Path, PathBuf},
@@ -129,6 +133,23 @@ pub fn with_output(self, output: &ProjectCompileOutput, base: &Path) -> Self {
         }))
     }
 
+    /// Add artifacts that aren't part of the compile output, e.g. the ones of Rust contracts.
+    pub fn with_artifacts(
+        mut self,
+        artifacts: impl IntoIterator<Item = (ArtifactId, CompactContractBytecode)>,
+    ) -> Self {
+        self.artifacts.extend(artifacts.into_iter().map(|(id, artifact)| {
+            let CompactContractBytecode { abi, bytecode, deployed_bytecode } = artifact;
+            let artifact = CompactContractBytecodeCow {
+                abi: abi.map(Cow::Owned),
+                bytecode: bytecode.map(Cow::Owned),
+                deployed_bytecode: deployed_bytecode.map(Cow::Owned),
+            };
+            (id, artifact)
+        }));
+        self
+    }
+
     /// Add storage layouts.
     pub fn with_storage_layouts(
         mut self,
@@ -243,6 +264,31 @@ fn find_by_code(
             .map(|(_, data)| data)
     }
 
+    /// Finds the Rust contract deployed with `init_code`.
+    ///
+    /// The init code is either the wasm module of the contract, with the constructor arguments
+    /// section if the contract has a constructor, or its rWasm module. Unlike
+    /// [`Self::find_by_creation_code`] the code has to match exactly.
+    pub fn find_rust_contract(&self, init_code: &[u8]) -> Option<ArtifactWithContractRef<'_>> {
+        let is_rwasm = wasm::is_rwasm(init_code);
+        if !is_rwasm && !wasm::is_wasm(init_code) {
+            return None;
+        }
+        let constructor_args =
+            if is_rwasm { None } else { wasm::constructor_args(init_code).ok()? };
+
+        self.iter().filter(|(id, _)| is_rust_artifact(id)).find(|(_, contract)| {
+            if is_rwasm {
+                return contract.deployed_bytecode().is_some_and(|code| code == init_code);
+            }
+            let Some(bytecode) = contract.bytecode() else { return false };
+            match &constructor_args {
+                Some(args) => wasm::with_constructor_args(bytecode, args) == init_code,
+                None => bytecode == init_code,
+            }
+        })
+    }
+
     /// Finds a contract which deployed bytecode exactly matches the given code. Accounts for link
     /// references and immutables.
     pub fn find_by_deployed_code_exact(&self, code: &[u8]) -> Option<ArtifactWithContractRef<'_>> {
@@ -661,4 +707,50 @@ fn find_by_deployed_code_exact_with_empty_deployed() {
 
         assert!(contracts.find_by_deployed_code_exact(&[]).is_none());
     }
+
+    #[test]
+    fn find_rust_contract() {
+        // (module (func))
+        let wasm = Bytes::from_static(&[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+            0x03, 0x02, 0x01, 0x00, 0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b,
+        ]);
+        let rwasm = Bytes::from_static(&[0xef, 0x52, 0x00, 0x01]);
+        let id = |path: &str, name: &str| ArtifactId {
+            path: PathBuf::from(path),
+            name: name.to_string(),
+            source: PathBuf::from("src/counter"),
+            version: semver::Version::new(0, 1, 0),
+            build_id: String::new(),
+            profile: "release".to_string(),
+        };
+        let contract = |code: &Bytes, deployed: &Bytes| CompactContractBytecode {
+            abi: Some(JsonAbi::default()),
+            bytecode: Some(CompactBytecode {
+                object: BytecodeObject::Bytecode(code.clone()),
+                source_map: None,
+                link_references: Default::default(),
+            }),
+            deployed_bytecode: Some(CompactDeployedBytecode {
+                bytecode: Some(CompactBytecode {
+                    object: BytecodeObject::Bytecode(deployed.clone()),
+                    source_map: None,
+                    link_references: Default::default(),
+                }),
+                immutable_references: Default::default(),
+            }),
+        };
+        let contracts = ContractsByArtifact::new([
+            (id("out/Counter.sol/Counter.json", "Counter"), contract(&wasm, &rwasm)),
+            (id("out/counter.wasm", "counter"), contract(&wasm, &rwasm)),
+        ]);
+
+        let find = |code: &[u8]| contracts.find_rust_contract(code).map(|(id, _)| id.name.as_str());
+        assert_eq!(find(&wasm), Some("counter"));
+        assert_eq!(find(&wasm::with_constructor_args(&wasm, &[0x2a; 32])), Some("counter"));
+        assert_eq!(find(&rwasm), Some("counter"));
+        assert_eq!(find(&wasm[..wasm.len() - 1]), None);
+        assert_eq!(find(&[0xef, 0x52, 0x00, 0x02]), None);
+        assert_eq!(find(&[0x60, 0x80]), None);
+    }
 }
@@ -36,9 +36,12 @@
 pub mod transactions;
 mod utils;
 pub mod version;
+pub mod wasm;
 
 pub use compile::Analysis;
 
+pub mod rust_artifact;
+pub mod rust_compiler;
 pub mod rust_contracts;
 
 pub use constants::*;
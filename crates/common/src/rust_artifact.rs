@@ -0,0 +1,447 @@
+//! Typed artifacts of Rust contracts.
+//!
+//! The fluentbase builder writes the artifacts of a contract to `<out>/<package>.wasm/`:
+//!
+//! - `foundry.json`: ABI, wasm bytecode and, for newer builders, the rWasm bytecode
+//! - `lib.rwasm`: the rWasm module
+//! - `abi.json`: the ABI
+//! - `interface.sol`: the generated Solidity interface
+//! - `metadata.json`: the build environment and configuration
+//!
+//! Only `foundry.json` is always generated, see [`RustBuildOptions::artifacts`].
+
+use crate::{
+    rust_compiler::{RUST_WASM_COMPILER_VERSION, RustBuildOptions, RustWasmCompiler},
+    rust_contracts::RustProjectInfo,
+    wasm,
+};
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::Bytes;
+use eyre::{Context, Result};
+use foundry_compilers::{
+    Artifact, ArtifactId, ProjectCompileOutput,
+    artifacts::{
+        Bytecode, BytecodeObject, CompactBytecode, CompactContractBytecode, Contract,
+        DeployedBytecode, Evm,
+    },
+};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// File name of the Foundry artifact.
+pub const FOUNDRY_ARTIFACT_FILENAME: &str = "foundry.json";
+
+/// File name of the rWasm module.
+pub const RWASM_ARTIFACT_FILENAME: &str = "lib.rwasm";
+
+/// File name of the generated Solidity interface.
+pub const INTERFACE_ARTIFACT_FILENAME: &str = "interface.sol";
+
+/// File name of the build metadata.
+pub const METADATA_ARTIFACT_FILENAME: &str = "metadata.json";
+
+/// The artifacts of a built Rust contract.
+#[derive(Clone, Debug)]
+pub struct RustArtifact {
+    /// The package name of the contract.
+    pub name: String,
+    /// The artifact directory, `<out>/<package>.wasm/`.
+    pub dir: PathBuf,
+    /// The contract ABI.
+    pub abi: JsonAbi,
+    /// The wasm module, deployed as creation code.
+    pub bytecode: Bytes,
+    /// The rWasm module the wasm module is translated to on deployment, if it was generated.
+    pub rwasm: Option<Bytes>,
+    /// `function signature -> selector`
+    pub method_identifiers: BTreeMap<String, String>,
+    /// Path to the generated Solidity interface, if it was generated.
+    pub interface: Option<PathBuf>,
+    /// The build metadata, if it was generated.
+    pub metadata: Option<RustArtifactMetadata>,
+}
+
+/// The build metadata of a Rust contract, `metadata.json`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RustArtifactMetadata {
+    /// The environment the contract was built in.
+    pub environment: RustBuildEnvironment,
+    /// The options the contract was built with, only recorded by gblend builds.
+    ///
+    /// Kept as recorded and parsed by [`RustArtifact::build_config`], so artifacts whose options
+    /// can't be read still load.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_config: Option<serde_json::Value>,
+}
+
+/// The environment a Rust contract was built in.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RustBuildEnvironment {
+    /// The fluentbase SDK the contract was built against.
+    pub fluentbase_sdk: FluentbaseSdkInfo,
+}
+
+/// The fluentbase SDK a Rust contract was built against.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FluentbaseSdkInfo {
+    /// The SDK release tag, e.g. `v0.4.0`.
+    pub git_tag: String,
+}
+
+/// The subset of the fluentbase `foundry.json` artifact we read.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FoundryJson {
+    abi: JsonAbi,
+    bytecode: CompactBytecode,
+    #[serde(default)]
+    deployed_bytecode: Option<CompactBytecode>,
+    #[serde(default)]
+    method_identifiers: BTreeMap<String, String>,
+}
+
+impl RustArtifact {
+    /// Loads the artifacts of a built crate.
+    ///
+    /// Fails if `foundry.json` is missing, or if any of the artifacts is malformed.
+    pub fn load(info: &RustProjectInfo, artifacts_root: &Path) -> Result<Self> {
+        let dir = info.artifact_dir(artifacts_root);
+        let foundry_json = dir.join(FOUNDRY_ARTIFACT_FILENAME);
+        if !foundry_json.is_file() {
+            eyre::bail!(
+                "artifact of Rust contract '{}' not found at {}, did you run `gblend build`?",
+                info.package_name,
+                foundry_json.display()
+            );
+        }
+        let artifact: FoundryJson = read_json(&foundry_json)?;
+
+        let bytecode = linked_bytes(artifact.bytecode.object)
+            .wrap_err_with(|| format!("invalid bytecode in {}", foundry_json.display()))?;
+        let rwasm = match artifact.deployed_bytecode {
+            Some(deployed) => Some(linked_bytes(deployed.object).wrap_err_with(|| {
+                format!("invalid deployed bytecode in {}", foundry_json.display())
+            })?),
+            None => {
+                let path = dir.join(RWASM_ARTIFACT_FILENAME);
+                path.is_file()
+                    .then(|| fs::read(&path))
+                    .transpose()
+                    .wrap_err_with(|| format!("failed to read {}", path.display()))?
+                    .map(Bytes::from)
+            }
+        };
+
+        let interface = Some(dir.join(INTERFACE_ARTIFACT_FILENAME)).filter(|path| path.is_file());
+        let metadata_path = dir.join(METADATA_ARTIFACT_FILENAME);
+        let metadata = metadata_path.is_file().then(|| read_json(&metadata_path)).transpose()?;
+
+        Ok(Self {
+            name: info.package_name.clone(),
+            dir,
+            abi: artifact.abi,
+            bytecode,
+            rwasm,
+            method_identifiers: artifact.method_identifiers,
+            interface,
+            metadata,
+        })
+    }
+
+    /// Returns the build metadata, failing if the `metadata` artifact wasn't generated.
+    pub fn metadata(&self) -> Result<&RustArtifactMetadata> {
+        self.metadata.as_ref().ok_or_else(|| {
+            eyre::eyre!(
+                "{} of Rust contract '{}' not found, build it with the `metadata` artifact",
+                self.dir.join(METADATA_ARTIFACT_FILENAME).display(),
+                self.name
+            )
+        })
+    }
+
+    /// Returns the options the contract was built with, see [`Self::metadata`].
+    ///
+    /// Fails if the contract wasn't built by gblend, which records them.
+    pub fn build_config(&self) -> Result<RustBuildOptions> {
+        let path = self.dir.join(METADATA_ARTIFACT_FILENAME);
+        let build_config = self.metadata()?.build_config.as_ref().ok_or_else(|| {
+            eyre::eyre!(
+                "build options of Rust contract '{}' are not recorded in {}, rebuild it with \
+                 `gblend build`",
+                self.name,
+                path.display()
+            )
+        })?;
+        RustBuildOptions::deserialize(build_config).wrap_err_with(|| {
+            format!("invalid build options of Rust contract '{}' in {}", self.name, path.display())
+        })
+    }
+
+    /// Returns the release tag of the fluentbase SDK the contract was built against, see
+    /// [`Self::metadata`].
+    pub fn sdk_version(&self) -> Result<&str> {
+        Ok(&self.metadata()?.environment.fluentbase_sdk.git_tag)
+    }
+
+    /// Returns the rWasm module, failing if the `rwasm` artifact wasn't generated.
+    pub fn rwasm(&self) -> Result<&Bytes> {
+        self.rwasm.as_ref().ok_or_else(|| {
+            eyre::eyre!(
+                "rWasm bytecode of Rust contract '{}' not found, build it with the `rwasm` artifact",
+                self.name
+            )
+        })
+    }
+
+    /// Checks the wasm module against Fluent's size limit, see [`wasm::check_code_size`], and warns
+    /// if the rWasm module, if generated, is oversized, see [`wasm::check_rwasm_size`].
+    pub fn check_code_size(&self) -> Result<()> {
+        wasm::check_code_size(&self.name, self.bytecode.len())?;
+        if let Some(warning) =
+            self.rwasm.as_ref().and_then(|rwasm| wasm::check_rwasm_size(&self.name, rwasm.len()))
+        {
+            sh_warn!("{warning}")?;
+        }
+        Ok(())
+    }
+
+    /// Returns the creation bytecode as a [`CompactBytecode`].
+    pub fn compact_bytecode(&self) -> CompactBytecode {
+        CompactBytecode {
+            object: BytecodeObject::Bytecode(self.bytecode.clone()),
+            source_map: None,
+            link_references: Default::default(),
+        }
+    }
+
+    /// Returns the [`ArtifactId`] of the contract, named after its package.
+    ///
+    /// The source is the crate directory and the path the artifact directory.
+    pub fn artifact_id(&self, info: &RustProjectInfo) -> ArtifactId {
+        ArtifactId {
+            path: self.dir.clone(),
+            name: self.name.clone(),
+            source: info.path.clone(),
+            version: RUST_WASM_COMPILER_VERSION,
+            build_id: String::new(),
+            profile: "release".to_string(),
+        }
+    }
+
+    /// Converts the artifact into a [`Contract`].
+    ///
+    /// The creation bytecode is the wasm module, the deployed bytecode the rWasm module.
+    pub fn into_contract(self) -> Contract {
+        Contract {
+            abi: Some(self.abi),
+            metadata: None,
+            userdoc: Default::default(),
+            devdoc: Default::default(),
+            ir: None,
+            storage_layout: Default::default(),
+            transient_storage_layout: Default::default(),
+            evm: Some(Evm {
+                assembly: None,
+                legacy_assembly: None,
+                bytecode: Some(bytecode(self.bytecode)),
+                deployed_bytecode: Some(DeployedBytecode {
+                    bytecode: self.rwasm.map(bytecode),
+                    immutable_references: Default::default(),
+                }),
+                method_identifiers: self.method_identifiers,
+                gas_estimates: None,
+            }),
+            ewasm: None,
+            ir_optimized: None,
+            ir_optimized_ast: None,
+        }
+    }
+}
+
+/// Returns true if `id` identifies a Rust contract, see [`RustArtifact::artifact_id`].
+pub fn is_rust_artifact(id: &ArtifactId) -> bool {
+    id.path.extension().is_some_and(|ext| ext == "wasm")
+}
+
+/// Returns the artifacts of the Rust contracts in `output`, e.g. to add them to the known
+/// contracts of traces and cheatcodes.
+///
+/// The artifacts are identified like [`RustArtifact::artifact_id`] does.
+pub fn compiled_rust_artifacts(
+    output: &ProjectCompileOutput<RustWasmCompiler>,
+    artifacts_root: &Path,
+) -> Vec<(ArtifactId, CompactContractBytecode)> {
+    output
+        .artifact_ids()
+        .map(|(id, artifact)| {
+            (rust_artifact_id(id, artifacts_root), artifact.clone().into_contract_bytecode())
+        })
+        .collect()
+}
+
+/// Converts the ID of a Rust contract in the compile output, whose source is the file of the crate
+/// the contract is attributed to, to the one of [`RustArtifact::artifact_id`].
+fn rust_artifact_id(id: ArtifactId, artifacts_root: &Path) -> ArtifactId {
+    let source = id
+        .source
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+        .unwrap_or(id.source);
+    ArtifactId {
+        path: artifacts_root.join(format!("{}.wasm", id.name)),
+        name: id.name,
+        source,
+        version: RUST_WASM_COMPILER_VERSION,
+        build_id: String::new(),
+        profile: "release".to_string(),
+    }
+}
+
+/// Reads and deserializes the JSON file at `path`.
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content =
+        fs::read_to_string(path).wrap_err_with(|| format!("failed to read {}", path.display()))?;
+    serde_json::from_str(&content).wrap_err_with(|| format!("invalid artifact {}", path.display()))
+}
+
+/// Returns the bytes of a bytecode object, wasm modules are never linked.
+fn linked_bytes(object: BytecodeObject) -> Result<Bytes> {
+    match object {
+        BytecodeObject::Bytecode(bytes) => Ok(bytes),
+        BytecodeObject::Unlinked(_) => eyre::bail!("bytecode contains unlinked references"),
+    }
+}
+
+fn bytecode(code: Bytes) -> Bytecode {
+    Bytecode {
+        function_debug_data: Default::default(),
+        object: BytecodeObject::Bytecode(code),
+        opcodes: None,
+        source_map: None,
+        generated_sources: Vec::new(),
+        link_references: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn info(root: &Path) -> RustProjectInfo {
+        RustProjectInfo {
+            path: root.join("src/counter"),
+            package_name: "counter".to_string(),
+            sdk_version: None,
+        }
+    }
+
+    #[test]
+    fn loads_artifacts() {
+        let temp_dir = TempDir::new().unwrap();
+        let info = info(temp_dir.path());
+        let out = temp_dir.path().join("out");
+        let dir = info.artifact_dir(&out);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(FOUNDRY_ARTIFACT_FILENAME),
+            r#"{"abi":[],"bytecode":{"object":"0x0061736d"},"methodIdentifiers":{"count()":"06661abd"}}"#,
+        )
+        .unwrap();
+        fs::write(dir.join(RWASM_ARTIFACT_FILENAME), [0xef, 0x52]).unwrap();
+
+        let artifact = RustArtifact::load(&info, &out).unwrap();
+        assert_eq!(artifact.bytecode, Bytes::from_static(&[0x00, 0x61, 0x73, 0x6d]));
+        assert_eq!(artifact.rwasm().unwrap(), &Bytes::from_static(&[0xef, 0x52]));
+        assert_eq!(artifact.method_identifiers["count()"], "06661abd");
+        assert!(artifact.interface.is_none());
+        let err = artifact.sdk_version().unwrap_err().to_string();
+        assert!(err.contains("metadata.json of Rust contract 'counter' not found"), "{err}");
+
+        // Missing fields fail instead of falling back to defaults
+        fs::write(dir.join(METADATA_ARTIFACT_FILENAME), r#"{"environment":{"fluentbase_sdk":{}}}"#)
+            .unwrap();
+        let err = format!("{:#}", RustArtifact::load(&info, &out).unwrap_err());
+        assert!(err.contains("missing field `git_tag`"), "{err}");
+
+        // Metadata of builds that didn't record the build options still loads
+        fs::write(
+            dir.join(METADATA_ARTIFACT_FILENAME),
+            r#"{"environment":{"fluentbase_sdk":{"git_tag":"v0.4.0"}}}"#,
+        )
+        .unwrap();
+        let artifact = RustArtifact::load(&info, &out).unwrap();
+        assert_eq!(artifact.sdk_version().unwrap(), "v0.4.0");
+        let err = artifact.build_config().unwrap_err().to_string();
+        assert!(err.contains("build options of Rust contract 'counter' are not recorded"), "{err}");
+
+        fs::write(
+            dir.join(METADATA_ARTIFACT_FILENAME),
+            r#"{"environment":{"fluentbase_sdk":{"git_tag":"v0.4.0"}},"build_config":{"features":[]}}"#,
+        )
+        .unwrap();
+        let artifact = RustArtifact::load(&info, &out).unwrap();
+        let err = format!("{:#}", artifact.build_config().unwrap_err());
+        assert!(err.contains("invalid build options of Rust contract 'counter'"), "{err}");
+        assert!(err.contains(&dir.join(METADATA_ARTIFACT_FILENAME).display().to_string()), "{err}");
+        assert!(err.contains("missing field"), "{err}");
+
+        let options = RustBuildOptions::default();
+        fs::write(
+            dir.join(METADATA_ARTIFACT_FILENAME),
+            serde_json::json!({
+                "environment": { "fluentbase_sdk": { "git_tag": "v0.4.0" } },
+                "build_config": options,
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let artifact = RustArtifact::load(&info, &out).unwrap();
+        assert_eq!(artifact.build_config().unwrap(), options);
+    }
+
+    #[test]
+    fn compiled_artifact_ids() {
+        let temp_dir = TempDir::new().unwrap();
+        let info = info(temp_dir.path());
+        fs::create_dir_all(info.path.join("src")).unwrap();
+        fs::write(info.path.join("Cargo.toml"), "[package]\nname = \"counter\"\n").unwrap();
+
+        let out = temp_dir.path().join("out");
+        let dir = info.artifact_dir(&out);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(FOUNDRY_ARTIFACT_FILENAME),
+            r#"{"abi":[],"bytecode":{"object":"0x0061736d"}}"#,
+        )
+        .unwrap();
+
+        // The compile output attributes the contract to the entry point of its crate
+        let compiled = ArtifactId {
+            path: out.join("lib.rs/counter.json"),
+            name: "counter".to_string(),
+            source: info.path.join("src/lib.rs"),
+            version: RUST_WASM_COMPILER_VERSION,
+            build_id: "0".to_string(),
+            profile: "default".to_string(),
+        };
+        let id = rust_artifact_id(compiled, &out);
+        assert_eq!(id, RustArtifact::load(&info, &out).unwrap().artifact_id(&info));
+        assert_eq!(id.source, info.path);
+        assert_eq!(id.path, dir);
+        assert!(is_rust_artifact(&id));
+    }
+
+    #[test]
+    fn missing_artifact() {
+        let temp_dir = TempDir::new().unwrap();
+        let err = RustArtifact::load(&info(temp_dir.path()), temp_dir.path()).unwrap_err();
+        assert!(err.to_string().contains("did you run `gblend build`?"), "{err}");
+    }
+}
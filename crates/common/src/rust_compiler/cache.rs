@@ -0,0 +1,156 @@
+//! Build fingerprints of Rust contracts.
+//!
+//! The foundry-compilers cache only tracks source files, while a Rust contract build also depends
+//! on the crate manifest, the lockfile, the fluentbase SDK tag and the build settings. Each crate
+//! gets a fingerprint over all of these, a crate is only rebuilt if its fingerprint changed.
+
+use super::{RUST_FILES_CACHE_FILENAME, RustBuildOptions, crate_sources};
+use crate::rust_contracts::RustProjectInfo;
+use alloy_primitives::{B256, Keccak256};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Name of the Rust contracts build cache file, kept next to the Solidity files cache.
+pub const RUST_BUILD_CACHE_FILENAME: &str = "rust-build-cache.json";
+
+/// Fingerprints of the last successful build of each Rust contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RustBuildCache {
+    /// `package name -> fingerprint`
+    pub crates: BTreeMap<String, B256>,
+}
+
+impl RustBuildCache {
+    /// Reads the cache file at `path`.
+    ///
+    /// A missing or unreadable cache file results in an empty cache, which rebuilds everything.
+    pub fn read(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes the cache file to `path`.
+    pub fn write(&self, path: &Path) -> eyre::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns true if `package` was last built with the given fingerprint.
+    pub fn is_fresh(&self, package: &str, fingerprint: B256) -> bool {
+        self.crates.get(package) == Some(&fingerprint)
+    }
+}
+
+/// Returns the lockfile used to build the crate at `root`.
+///
+/// This is the crate's own `Cargo.lock`, or the one of the enclosing workspace.
+pub fn crate_lockfile(root: &Path) -> Option<PathBuf> {
+    root.ancestors().map(|dir| dir.join("Cargo.lock")).find(|path| path.is_file())
+}
+
+/// Computes the build fingerprint of a crate.
+///
+/// Covers all `.rs` sources, `build.rs`, `Cargo.toml`, `Cargo.lock`, the SDK docker tag and the
+/// build options of the crate.
+pub fn crate_fingerprint(
+    info: &RustProjectInfo,
+    docker_tag: &str,
+    options: &RustBuildOptions,
+) -> eyre::Result<B256> {
+    let mut hasher = Keccak256::new();
+
+    let build_script = info.path.join("build.rs");
+    let files = crate_sources(&info.path)
+        .into_iter()
+        .chain(build_script.is_file().then_some(build_script))
+        .chain(Some(info.path.join("Cargo.toml")))
+        .chain(crate_lockfile(&info.path));
+    for file in files {
+        let relative = file.strip_prefix(&info.path).unwrap_or(&file);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update(fs::read(&file)?);
+    }
+
+    hasher.update(docker_tag.as_bytes());
+    hasher.update(serde_json::to_vec(options)?);
+
+    Ok(hasher.finalize())
+}
+
+/// Removes the Rust contracts caches from the project's cache directory.
+pub fn clean_rust_caches(cache_dir: &Path) {
+    for file in [RUST_FILES_CACHE_FILENAME, RUST_BUILD_CACHE_FILENAME] {
+        let _ = fs::remove_file(cache_dir.join(file));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project_info(dir: &Path) -> RustProjectInfo {
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("Cargo.toml"), "[package]\nname = \"counter\"\n").unwrap();
+        fs::write(dir.join("src/lib.rs"), "fn main() {}").unwrap();
+        RustProjectInfo {
+            path: dir.to_path_buf(),
+            package_name: "counter".to_string(),
+            sdk_version: None,
+        }
+    }
+
+    #[test]
+    fn fingerprint_tracks_build_inputs() {
+        let temp_dir = TempDir::new().unwrap();
+        let info = project_info(temp_dir.path());
+        let options = RustBuildOptions::default();
+
+        let initial = crate_fingerprint(&info, "v1.2.0", &options).unwrap();
+        assert_eq!(initial, crate_fingerprint(&info, "v1.2.0", &options).unwrap());
+
+        // SDK tag and build options
+        assert_ne!(initial, crate_fingerprint(&info, "v1.3.0", &options).unwrap());
+        let no_docker = RustBuildOptions { docker: false, ..Default::default() };
+        assert_ne!(initial, crate_fingerprint(&info, "v1.2.0", &no_docker).unwrap());
+        let features = RustBuildOptions { features: vec!["std".to_string()], ..Default::default() };
+        assert_ne!(initial, crate_fingerprint(&info, "v1.2.0", &features).unwrap());
+
+        // Lockfile of the enclosing directory
+        fs::write(temp_dir.path().join("Cargo.lock"), "version = 4").unwrap();
+        let locked = crate_fingerprint(&info, "v1.2.0", &options).unwrap();
+        assert_ne!(initial, locked);
+
+        // Sources
+        fs::write(temp_dir.path().join("src/lib.rs"), "fn main() { }").unwrap();
+        assert_ne!(locked, crate_fingerprint(&info, "v1.2.0", &options).unwrap());
+    }
+
+    #[test]
+    fn cache_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("cache").join(RUST_BUILD_CACHE_FILENAME);
+
+        assert_eq!(RustBuildCache::read(&path), RustBuildCache::default());
+
+        let mut cache = RustBuildCache::default();
+        cache.crates.insert("counter".to_string(), B256::repeat_byte(1));
+        cache.write(&path).unwrap();
+
+        let cache = RustBuildCache::read(&path);
+        assert!(cache.is_fresh("counter", B256::repeat_byte(1)));
+        assert!(!cache.is_fresh("counter", B256::repeat_byte(2)));
+        assert!(!cache.is_fresh("erc20", B256::repeat_byte(1)));
+
+        clean_rust_caches(path.parent().unwrap());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,175 @@
+//! Structured diagnostics of Rust contracts.
+//!
+//! The fluentbase builder only reports whether a build failed. To report rustc errors and warnings
+//! like Solidity compiler diagnostics, every crate is checked with
+//! `cargo check --message-format=json` before it is built, and the emitted diagnostics are turned
+//! into [`RustWasmCompilationError`]s. They are filtered like Solidity diagnostics, e.g. by
+//! `ignored_error_codes` and `deny_warnings`.
+
+use super::{RustBuildOptions, RustWasmCompilationError, WASM_TARGET};
+use crate::rust_contracts::RustProjectInfo;
+use foundry_compilers::artifacts::{Severity, error::SourceLocation};
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+/// A line of `cargo --message-format=json` output.
+#[derive(serde::Deserialize)]
+struct CargoMessage {
+    reason: String,
+    #[serde(default)]
+    message: Option<RustcDiagnostic>,
+}
+
+/// A rustc diagnostic, see <https://doc.rust-lang.org/rustc/json.html>.
+#[derive(serde::Deserialize)]
+struct RustcDiagnostic {
+    message: String,
+    code: Option<RustcCode>,
+    level: String,
+    #[serde(default)]
+    spans: Vec<RustcSpan>,
+    rendered: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct RustcCode {
+    code: String,
+}
+
+#[derive(serde::Deserialize)]
+struct RustcSpan {
+    file_name: String,
+    byte_start: i32,
+    byte_end: i32,
+    is_primary: bool,
+}
+
+/// Checks the crate with `cargo check` and returns the reported diagnostics.
+///
+/// Source locations are relative to `root`. The crate is checked with the pinned toolchain of
+/// both build modes, which must be installed on the host.
+pub fn check_crate(
+    info: &RustProjectInfo,
+    options: &RustBuildOptions,
+    root: &Path,
+) -> eyre::Result<Vec<RustWasmCompilationError>> {
+    let mut cmd = Command::new("cargo");
+    cmd.current_dir(&info.path)
+        .args(["check", "--lib", "--release", "--message-format=json", "--target", WASM_TARGET])
+        .arg("--manifest-path")
+        .arg(info.path.join("Cargo.toml"));
+    if !options.features.is_empty() {
+        cmd.arg("--features").arg(options.features.join(","));
+    }
+    if options.no_default_features {
+        cmd.arg("--no-default-features");
+    }
+    if options.locked {
+        cmd.arg("--locked");
+    }
+    cmd.env("RUSTUP_TOOLCHAIN", &options.toolchain);
+    trace!(target: "forge::compile", package = %info.package_name, ?cmd, "checking rust contract");
+
+    let output = cmd.stdin(Stdio::null()).stderr(Stdio::null()).output()?;
+    Ok(parse_diagnostics(&String::from_utf8_lossy(&output.stdout), &info.path, root))
+}
+
+/// Parses the diagnostics of `cargo --message-format=json` output.
+///
+/// `crate_root` is used to resolve the workspace relative file names reported by cargo.
+fn parse_diagnostics(
+    output: &str,
+    crate_root: &Path,
+    root: &Path,
+) -> Vec<RustWasmCompilationError> {
+    output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CargoMessage>(line).ok())
+        .filter(|msg| msg.reason == "compiler-message")
+        .filter_map(|msg| msg.message)
+        .filter_map(|diagnostic| {
+            let severity = match diagnostic.level.as_str() {
+                level if level.starts_with("error") => Severity::Error,
+                "warning" => Severity::Warning,
+                _ => return None,
+            };
+            // Summaries like "aborting due to 2 previous errors" or "1 warning emitted"
+            if diagnostic.spans.is_empty() && diagnostic.code.is_none() {
+                return None;
+            }
+
+            let source_location =
+                diagnostic.spans.iter().find(|span| span.is_primary).map(|span| {
+                    let file = resolve_file(&span.file_name, crate_root);
+                    let file = file.strip_prefix(root).unwrap_or(&file);
+                    SourceLocation {
+                        file: file.to_string_lossy().into_owned(),
+                        start: span.byte_start,
+                        end: span.byte_end,
+                    }
+                });
+
+            Some(RustWasmCompilationError {
+                source_location,
+                severity,
+                error_code: diagnostic.code.map(|code| code.code),
+                message: diagnostic.message,
+                formatted_message: diagnostic.rendered,
+            })
+        })
+        .collect()
+}
+
+/// Resolves a file name reported by cargo, which is relative to the workspace root.
+fn resolve_file(file_name: &str, crate_root: &Path) -> PathBuf {
+    let path = Path::new(file_name);
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    crate_root
+        .ancestors()
+        .map(|dir| dir.join(path))
+        .find(|candidate| candidate.is_file())
+        .unwrap_or_else(|| crate_root.join(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use foundry_compilers::CompilationError;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_cargo_diagnostics() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let crate_root = root.join("src/counter");
+        fs::create_dir_all(crate_root.join("src")).unwrap();
+        fs::write(crate_root.join("src/lib.rs"), "").unwrap();
+
+        let output = r#"{"reason":"compiler-artifact","package_id":"counter"}
+{"reason":"compiler-message","message":{"message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":11,"is_primary":true}],"rendered":"warning: unused variable: `x`\n"}}
+{"reason":"compiler-message","message":{"message":"mismatched types","code":{"code":"E0308","explanation":null},"level":"error","spans":[{"file_name":"src/lib.rs","byte_start":20,"byte_end":24,"is_primary":true}],"rendered":"error[E0308]: mismatched types\n"}}
+{"reason":"compiler-message","message":{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"rendered":"error: aborting due to 1 previous error\n"}}
+{"reason":"build-finished","success":false}"#;
+
+        let diagnostics = parse_diagnostics(output, &crate_root, root);
+        assert_eq!(diagnostics.len(), 2);
+
+        let warning = &diagnostics[0];
+        assert!(warning.is_warning());
+        assert_eq!(warning.error_code(), None);
+        assert_eq!(
+            warning.source_location(),
+            Some(SourceLocation { file: "src/counter/src/lib.rs".to_string(), start: 10, end: 11 })
+        );
+
+        let error = &diagnostics[1];
+        assert!(error.is_error());
+        assert_eq!(error.error_code(), Some(308));
+        assert_eq!(error.to_string(), "error[E0308]: mismatched types");
+    }
+}
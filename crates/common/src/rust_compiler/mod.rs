@@ -1,22 +1,75 @@
+//! Rust/WASM compiler backend for [`foundry_compilers`].
+//!
+//! Rust contracts are regular Cargo crates living in the project's source directory. This module
+//! plugs them into the foundry-compilers pipeline: every `.rs` file of a crate is a source, the
+//! crate entry point (`src/lib.rs`) "imports" the rest of the crate so that any change marks the
+//! whole crate dirty, and compiling the entry point runs [`fluentbase_build::execute_build`] and
+//! turns the generated `foundry.json` into a [`Contract`].
+//!
+//! Crates are additionally fingerprinted, see [`RustBuildCache`], so unchanged crates are never
+//! rebuilt. Dirty crates are built in parallel, see [`RustWasmCompiler::with_jobs`].
+//!
+//! Crates are built inside the fluentbase Docker image by default. Native builds pin the toolchain
+//! through rustup instead, see [`ensure_toolchain`], and can be compared against a Docker build with
+//! [`RustWasmCompiler::with_check_reproducible`].
+
+use crate::{
+    rust_artifact::{METADATA_ARTIFACT_FILENAME, RustArtifact},
+    rust_contracts::{RustContractsRegistry, RustProjectInfo},
+};
+use alloy_primitives::B256;
+use fluentbase_build::{Artifact as FluentArtifact, BuildArgs, DEFAULT_DOCKER_TAG, execute_build};
 use foundry_compilers::{
+    CompilationError, Compiler, CompilerOutput, CompilerSettings, CompilerSettingsRestrictions,
+    CompilerVersion, Language, ParsedSource, ProjectPathsConfig, SourceParser,
     artifacts::{
-        error::SourceLocation, output_selection::OutputSelection, sources::Source, Contract, EvmVersion,
-        Severity, Sources,
-    }, solc::Restriction, CompilationError, Compiler,
-    CompilerOutput, CompilerSettings, CompilerSettingsRestrictions, CompilerVersion, Language,
-    ParsedSource,
-    ProjectPathsConfig,
-    SourceParser,
+        Contract, Severity, Sources, error::SourceLocation, output_selection::OutputSelection,
+        sources::Source,
+    },
 };
+use foundry_config::{Config, RustBuildConfig};
+use rayon::prelude::*;
 use semver::{Version, VersionReq};
 use std::{
     borrow::Cow,
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt,
     fmt::Display,
+    fs,
     path::{Path, PathBuf},
+    time::Instant,
+};
+use walkdir::WalkDir;
+
+mod diagnostics;
+pub use diagnostics::check_crate;
+
+mod cache;
+pub use cache::{
+    RUST_BUILD_CACHE_FILENAME, RustBuildCache, clean_rust_caches, crate_fingerprint, crate_lockfile,
 };
 
+mod native;
+pub use native::{check_builder_version, ensure_toolchain, is_toolchain_installed, rwasm_hash};
+
+/// The version reported for Rust/WASM artifacts.
+///
+/// The actual toolchain is pinned by the fluentbase build image, so this only serves as a stable
+/// version key for artifacts and cache entries.
+pub const RUST_WASM_COMPILER_VERSION: Version = Version::new(0, 1, 0);
+
+/// Name of the cache file for Rust contracts, kept next to the Solidity files cache.
+pub const RUST_FILES_CACHE_FILENAME: &str = "rust-files-cache.json";
+
+/// Rust toolchain used to build contracts.
+pub const RUST_TOOLCHAIN_VERSION: &str = "1.92.0-x86_64-unknown-linux-gnu";
+
+/// The target Rust contracts are compiled for.
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Directory inside the artifacts directory the reproducibility check builds into.
+const REPRODUCIBILITY_DIR: &str = ".rust-reproducibility";
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub struct RustWasmLanguage;
@@ -36,8 +89,8 @@ fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
         D: serde::Deserializer<'de>,
     {
         let res = String::deserialize(deserializer)?;
-        if res != "vyper" {
-            Err(serde::de::Error::custom(format!("Invalid Vyper language: {res}")))
+        if res != "rust" {
+            Err(serde::de::Error::custom(format!("Invalid Rust language: {res}")))
         } else {
             Ok(Self)
         }
@@ -54,83 +107,225 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     }
 }
 
-// --- 2. Compilation Error ---
-// A minimal error type to satisfy the `CompilationError` trait.
-
+/// A diagnostic produced while building a Rust contract.
+///
+/// This is either a rustc diagnostic, see [`check_crate`], or a failure of the build itself.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub struct RustWasmCompilationError {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_location: Option<SourceLocation>,
+    pub severity: Severity,
+    /// The rustc error code, e.g. `E0308`, or lint name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     pub message: String,
+    /// The diagnostic as rendered by rustc.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted_message: Option<String>,
+}
+
+impl RustWasmCompilationError {
+    /// Creates an error without source location.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            source_location: None,
+            severity: Severity::Error,
+            error_code: None,
+            message: message.into(),
+            formatted_message: None,
+        }
+    }
 }
 
 impl CompilationError for RustWasmCompilationError {
     fn is_warning(&self) -> bool {
-        false // Assuming all our compiler errors are critical
+        self.severity.is_warning()
     }
     fn is_error(&self) -> bool {
-        true
+        self.severity.is_error()
     }
     fn source_location(&self) -> Option<SourceLocation> {
-        None // Can be improved to parse location from Cargo's output
+        self.source_location.clone()
     }
     fn severity(&self) -> Severity {
-        Severity::Error
+        self.severity
     }
+    /// Numeric part of rustc error codes, `E0308` is `308`. Lints don't have one.
     fn error_code(&self) -> Option<u64> {
-        None
+        self.error_code.as_deref()?.strip_prefix('E')?.parse().ok()
     }
 }
 
 impl Display for RustWasmCompilationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.message)
+        match &self.formatted_message {
+            Some(formatted) => f.write_str(formatted.trim_end()),
+            None => write!(f, "{}: {}", self.severity, self.message),
+        }
     }
 }
 
-// --- 3. Compiler Settings ---
-// Minimal settings struct. For Rust, most settings are in `Cargo.toml`.
-// We can add fields here if we need to pass specific command-line args.
+/// Settings passed to the fluentbase builder.
+///
+/// Most of the build configuration lives in each crate's `Cargo.toml`, the rest can be set per
+/// package.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RustWasmSettings {
+    /// Build options of packages without overrides.
+    pub defaults: RustBuildOptions,
+    /// Build options of individual packages, keyed by package name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub packages: BTreeMap<String, RustBuildOptions>,
+}
+
+impl RustWasmSettings {
+    /// Returns the settings used to build the Rust contracts of the project, taken from the
+    /// `[rust]` section of the [`Config`].
+    pub fn from_config(config: &Config) -> Self {
+        let options = |build: RustBuildConfig| {
+            let defaults = RustBuildOptions::default();
+            RustBuildOptions {
+                docker: !config.no_docker,
+                docker_tag: build.docker_tag,
+                features: build.features.unwrap_or(defaults.features),
+                no_default_features: build
+                    .no_default_features
+                    .unwrap_or(defaults.no_default_features),
+                wasm_opt: build.wasm_opt.unwrap_or(defaults.wasm_opt),
+                locked: build.locked.unwrap_or(defaults.locked),
+                toolchain: build.toolchain.unwrap_or(defaults.toolchain),
+                artifacts: build
+                    .artifacts
+                    .map(|artifacts| artifacts.iter().map(ToString::to_string).collect())
+                    .unwrap_or(defaults.artifacts),
+            }
+        };
+
+        Self {
+            defaults: options(config.rust.build.clone()),
+            packages: config
+                .rust
+                .packages
+                .keys()
+                .map(|name| (name.clone(), options(config.rust.package(name))))
+                .collect(),
+        }
+    }
 
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
-pub struct RustWasmSettings;
+    /// Returns the build options of the given package.
+    pub fn options(&self, package: &str) -> &RustBuildOptions {
+        self.packages.get(package).unwrap_or(&self.defaults)
+    }
+}
+
+/// Build options of a single Rust contract.
+///
+/// These are recorded in the `build_config` of the contract's `metadata.json` so the build can be
+/// reproduced during verification.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RustBuildOptions {
+    /// Whether to build inside the fluentbase docker image for reproducible output.
+    pub docker: bool,
+    /// The tag of the fluentbase build image, derived from the crate's SDK version if not set.
+    pub docker_tag: Option<String>,
+    /// Cargo features to activate.
+    pub features: Vec<String>,
+    /// Whether to disable the default features of the crate.
+    pub no_default_features: bool,
+    /// Whether to optimize the wasm module with `wasm-opt`.
+    pub wasm_opt: bool,
+    /// Whether to require `Cargo.lock` to be up to date.
+    pub locked: bool,
+    /// The Rust toolchain to build with.
+    pub toolchain: String,
+    /// The artifacts to generate: `solidity`, `abi`, `foundry`, `rwasm` and `metadata`.
+    ///
+    /// `foundry` is always generated, it is needed to load the contract.
+    pub artifacts: Vec<String>,
+}
+
+impl Default for RustBuildOptions {
+    fn default() -> Self {
+        Self {
+            docker: true,
+            docker_tag: None,
+            features: Vec::new(),
+            no_default_features: false,
+            wasm_opt: false,
+            locked: false,
+            toolchain: RUST_TOOLCHAIN_VERSION.to_string(),
+            artifacts: ["solidity", "abi", "foundry", "rwasm", "metadata"]
+                .map(String::from)
+                .to_vec(),
+        }
+    }
+}
+
+impl RustBuildOptions {
+    /// Returns the fluentbase artifacts to generate.
+    fn fluent_artifacts(&self) -> Result<Vec<FluentArtifact>, String> {
+        let mut artifacts = vec![FluentArtifact::Foundry];
+        for name in &self.artifacts {
+            let artifact = match name.as_str() {
+                "solidity" => FluentArtifact::Solidity,
+                "abi" => FluentArtifact::Abi,
+                "foundry" => continue,
+                "rwasm" => FluentArtifact::Rwasm,
+                "metadata" => FluentArtifact::Metadata,
+                _ => return Err(format!("Unknown Rust contract artifact: {name}")),
+            };
+            artifacts.push(artifact);
+        }
+        Ok(artifacts)
+    }
+}
 
 impl CompilerSettings for RustWasmSettings {
-    type Restrictions = RustRestrictions;
+    type Restrictions = RustWasmRestrictions;
+
     fn update_output_selection(&mut self, _f: impl FnOnce(&mut OutputSelection)) {
-        // Not applicable to Rust/WASM build process
-        unimplemented!()
+        // The fluentbase builder always emits the full set of artifacts.
     }
-    fn can_use_cached(&self, _other: &Self) -> bool {
-        // For simplicity, we can always recompile. Caching can be implemented later.
-        false
+    fn can_use_cached(&self, other: &Self) -> bool {
+        self == other
     }
-    fn with_include_paths(self, _include_paths: &std::collections::BTreeSet<PathBuf>) -> Self {
-        self // Not applicable
+    fn with_include_paths(self, _include_paths: &BTreeSet<PathBuf>) -> Self {
+        self
     }
     fn satisfies_restrictions(&self, _restrictions: &Self::Restrictions) -> bool {
         true
     }
 }
 
+/// Rust contracts can't be restricted per file, this only satisfies the trait bounds.
 #[derive(Clone, Copy, Debug, Default)]
-pub struct RustRestrictions {
-    pub evm_version: Restriction<EvmVersion>,
-}
+pub struct RustWasmRestrictions;
 
-impl CompilerSettingsRestrictions for RustRestrictions {
-    fn merge(self, other: Self) -> Option<Self> {
-        Some(Self { evm_version: self.evm_version.merge(other.evm_version)? })
+impl CompilerSettingsRestrictions for RustWasmRestrictions {
+    fn merge(self, _other: Self) -> Option<Self> {
+        Some(self)
     }
 }
 
-// --- 4. Source Parser ---
-// Minimal implementation for `ParsedSource`. Rust dependencies are managed
-// by Cargo, so we don't need to parse import paths from source files.
-
+/// A parsed `.rs` file.
+///
+/// Rust dependencies are resolved by Cargo, so the only "imports" we track are the files of a
+/// crate: its modules, `build.rs`, `Cargo.toml` and `Cargo.lock`. These are attributed to the crate
+/// entry point.
 #[derive(Clone, Debug)]
 pub struct RustWasmParsedSource {
     path: PathBuf,
 }
 
+impl RustWasmParsedSource {
+    /// Returns the crate root if this file is the entry point of a crate.
+    fn crate_root(&self) -> Option<&Path> {
+        let root = self.path.parent()?.parent()?;
+        (self.path.file_name()? == "lib.rs" && root.join("Cargo.toml").is_file()).then_some(root)
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct RustWasmParser;
 
@@ -149,10 +344,10 @@ fn parse(_content: &str, file: &Path) -> foundry_compilers::error::Result<Self>
         Ok(Self { path: file.to_path_buf() })
     }
     fn version_req(&self) -> Option<&VersionReq> {
-        None // Version is managed by the Rust toolchain, not in-file pragmas
+        None
     }
     fn contract_names(&self) -> &[String] {
-        &[] // The contract name comes from the Cargo package name
+        &[]
     }
     fn language(&self) -> Self::Language {
         RustWasmLanguage
@@ -160,15 +355,21 @@ fn language(&self) -> Self::Language {
     fn resolve_imports<C>(
         &self,
         _paths: &ProjectPathsConfig<C>,
-        _include_paths: &mut std::collections::BTreeSet<PathBuf>,
+        _include_paths: &mut BTreeSet<PathBuf>,
     ) -> foundry_compilers::error::Result<Vec<PathBuf>> {
-        Ok(Vec::new()) // Cargo handles all dependency resolution
+        let Some(root) = self.crate_root() else { return Ok(Vec::new()) };
+        let build_script = root.join("build.rs");
+        Ok(crate_sources(root)
+            .into_iter()
+            .filter(|path| *path != self.path)
+            .chain(build_script.is_file().then_some(build_script))
+            .chain(Some(root.join("Cargo.toml")))
+            .chain(crate_lockfile(root))
+            .collect())
     }
 }
 
-// --- 5. Compiler Input ---
-// The input struct that holds sources and settings, similar to `VyperVersionedInput`.
-
+/// The input for a single Rust/WASM compiler invocation.
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct RustWasmInput {
     pub sources: Sources,
@@ -190,7 +391,7 @@ fn build(
         Self { sources, settings, version }
     }
     fn compiler_name(&self) -> Cow<'static, str> {
-        "RustWasmCompiler".into()
+        "RustWasm".into()
     }
     fn strip_prefix(&mut self, base: &Path) {
         self.sources = self
@@ -212,15 +413,68 @@ fn sources(&self) -> impl Iterator<Item = (&Path, &Source)> {
     }
 }
 
-// --- 6. The Compiler ---
-// The main compiler struct. It's stateless in this minimal implementation.
-
+/// Builds Rust contracts with the fluentbase builder.
 #[derive(Debug, Clone, Default)]
-pub struct RustWasmCompiler;
+pub struct RustWasmCompiler {
+    /// The project root, mounted into the build container.
+    root: PathBuf,
+    /// The artifacts directory, fluentbase artifacts are written to `<artifacts>/<pkg>.wasm/`.
+    artifacts: PathBuf,
+    /// Path to the [`RustBuildCache`] file, if caching is enabled.
+    build_cache: Option<PathBuf>,
+    /// Maximum number of crates built in parallel, `0` uses the number of logical cores.
+    jobs: usize,
+    /// Whether to suppress per-crate progress output.
+    quiet: bool,
+    /// Whether to build every crate both natively and with Docker and compare the rWasm output.
+    check_reproducible: bool,
+}
 
 impl RustWasmCompiler {
-    /// A helper function to find the root of a Cargo project from a source file.
-    fn find_project_root(path: &Path) -> Option<PathBuf> {
+    /// Creates a new compiler for the project at `root` writing artifacts to `artifacts`.
+    pub fn new(root: impl Into<PathBuf>, artifacts: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            artifacts: artifacts.into(),
+            build_cache: None,
+            jobs: 0,
+            quiet: false,
+            check_reproducible: false,
+        }
+    }
+
+    /// Sets the [`RustBuildCache`] file used to skip rebuilding unchanged crates.
+    pub fn with_build_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.build_cache = Some(path.into());
+        self
+    }
+
+    /// Sets the maximum number of crates built in parallel.
+    ///
+    /// `0` uses the number of logical cores. This does not affect the build output and is
+    /// therefore not part of [`RustWasmSettings`].
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs;
+        self
+    }
+
+    /// Suppresses the per-crate progress output.
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Sets whether to check that crates build to the same rWasm module natively and with Docker.
+    ///
+    /// Every crate is built a second time in the other mode and the build fails if the rWasm
+    /// hashes differ. Crates are always rebuilt when checking.
+    pub fn with_check_reproducible(mut self, check: bool) -> Self {
+        self.check_reproducible = check;
+        self
+    }
+
+    /// Finds the root of the Cargo crate containing `path`.
+    fn find_crate_root(path: &Path) -> Option<PathBuf> {
         let mut current = path.parent()?;
         loop {
             if current.join("Cargo.toml").exists() {
@@ -230,63 +484,307 @@ fn find_project_root(path: &Path) -> Option<PathBuf> {
         }
     }
 
-    /// A helper to normalize contract directory name to PascalCase contract name.
-    /// This is taken directly from your example.
-    fn normalize_contract_name(contract_dir: &str) -> String {
-        contract_dir
-            .split('-')
-            .filter(|word| !word.is_empty())
-            .map(|word| {
-                let mut chars = word.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+    /// Returns the docker tag of the fluentbase build image.
+    ///
+    /// This is the configured tag, or the one matching the crate's SDK version.
+    fn docker_tag(info: &RustProjectInfo, options: &RustBuildOptions) -> String {
+        let tag = options
+            .docker_tag
+            .clone()
+            .or_else(|| info.sdk_version.clone())
+            .unwrap_or_else(|| DEFAULT_DOCKER_TAG.to_string());
+        if tag.starts_with('v') { tag } else { format!("v{tag}") }
+    }
+
+    /// Builds a single crate and loads the resulting artifact.
+    ///
+    /// The build is skipped if the crate's fingerprint matches the one in `cache` and its artifact
+    /// is still present. Returns the contract and the fingerprint it was built with, rustc
+    /// diagnostics of the build are added to `diagnostics`.
+    fn compile_crate(
+        &self,
+        info: &RustProjectInfo,
+        settings: &RustWasmSettings,
+        cache: &RustBuildCache,
+        diagnostics: &mut Vec<RustWasmCompilationError>,
+    ) -> Result<(Contract, B256), String> {
+        let options = settings.options(&info.package_name);
+        let docker_tag = Self::docker_tag(info, options);
+        let fingerprint = crate_fingerprint(info, &docker_tag, options).map_err(|err| {
+            format!("Failed to fingerprint Rust contract '{}': {err}", info.package_name)
+        })?;
+
+        if !self.check_reproducible
+            && cache.is_fresh(&info.package_name, fingerprint)
+            && let Ok(contract) = load_foundry_artifact(info, &self.artifacts)
+        {
+            trace!(target: "forge::compile", package = %info.package_name, "crate unchanged");
+            return Ok((contract, fingerprint));
+        }
+
+        if !options.docker {
+            self.prepare_native_build(info, options)?;
+            diagnostics.extend(check_builder_version(&info.package_name, &docker_tag));
+        }
+
+        if !self.quiet {
+            let _ = sh_println!("Compiling {}...", info.package_name);
+        }
+        let start = Instant::now();
+
+        // The builder doesn't report rustc diagnostics, so the crate is checked first. Errors are
+        // reported with their source locations without running the build, warnings are reported
+        // like Solidity compiler warnings.
+        diagnostics.extend(self.check_crate(info, options));
+        let built = if diagnostics.iter().any(|diagnostic| diagnostic.is_error()) {
+            Err(format!("Failed to build Rust contract '{}'", info.package_name))
+        } else {
+            let build_args =
+                self.build_args(info, options, &docker_tag, options.docker, &self.artifacts)?;
+            execute_build(&build_args, Some(info.path.clone())).map_err(|err| {
+                format!(
+                    "Failed to build Rust contract '{}' at {}: {err}",
+                    info.package_name,
+                    info.path.display()
+                )
+            })
+        };
+
+        let result = built
+            .and_then(|_| {
+                if self.check_reproducible {
+                    self.verify_reproducible(info, options, &docker_tag)
+                } else {
+                    Ok(())
                 }
             })
-            .collect()
-    }
-
-    /// A helper that encapsulates the core build logic for a single Rust project.
-    /// This is where the logic from your `build_rust_contracts` function is adapted.
-    fn compile_rust_project(
-        project_path: &Path,
-    ) -> std::result::Result<(String, Contract), String> {
-        // This is a placeholder for your actual build logic using your internal builder.
-        // For this example, we'll simulate a successful build that produces a `Contract`.
-        // Replace the following simulation with your `execute_build` call.
-
-        let contract_dir_name = project_path.file_name().unwrap().to_str().unwrap();
-        let contract_name = Self::normalize_contract_name(contract_dir_name);
-
-        println!("    - Compiling project: {}", project_path.display());
-
-        // --- START: Replace this with your actual build logic ---
-        // Example:
-        // let build_args = BuildArgs { ... };
-        // let build_result = execute_build(&build_args, Some(project_path.to_path_buf()))
-        //     .map_err(|e| e.to_string())?;
-        // let foundry_artifact_path = build_result.foundry_metadata_path.unwrap();
-        // let contract_json = std::fs::read_to_string(foundry_artifact_path).unwrap();
-        // let contract: Contract = serde_json::from_str(&contract_json).unwrap();
-        // --- END: Replacement block ---
-
-        // Simulating a successful build for demonstration
-        let contract = Contract {
-            abi: None,
-            metadata: None,
-            userdoc: Default::default(),
-            devdoc: Default::default(),
-            ir: None,
-            storage_layout: Default::default(),
-            transient_storage_layout: Default::default(),
-            evm: None,
-            ewasm: None,
-            ir_optimized: None,
-            ir_optimized_ast: None,
-        }; // In reality, this is parsed from foundry.json
-        println!("    - Successfully compiled contract: {}", contract_name);
-
-        Ok((contract_name, contract))
+            .and_then(|_| {
+                record_build_options(info, &self.artifacts, &docker_tag, options).map_err(|err| {
+                    format!(
+                        "Failed to record build options of Rust contract '{}': {err}",
+                        info.package_name
+                    )
+                })
+            })
+            .and_then(|_| {
+                load_foundry_artifact(info, &self.artifacts).map_err(|err| {
+                    format!(
+                        "Failed to load artifact of Rust contract '{}': {err}",
+                        info.package_name
+                    )
+                })
+            });
+
+        if !self.quiet {
+            let _ = match &result {
+                Ok(_) => sh_println!("Compiled {} in {:.2?}", info.package_name, start.elapsed()),
+                Err(_) => sh_println!("Failed to compile {}", info.package_name),
+            };
+        }
+
+        result.map(|contract| (contract, fingerprint))
+    }
+
+    /// Builds a single crate with the given options and loads its artifacts.
+    ///
+    /// Unlike [`Compiler::compile`], the build cache is neither read nor updated. This is used to
+    /// reproduce the build of a deployed contract from the options recorded in its metadata.
+    pub fn build_crate(
+        &self,
+        info: &RustProjectInfo,
+        options: &RustBuildOptions,
+    ) -> eyre::Result<RustArtifact> {
+        let docker_tag = Self::docker_tag(info, options);
+        if !options.docker {
+            self.prepare_native_build(info, options).map_err(eyre::Error::msg)?;
+        }
+
+        if !self.quiet {
+            let _ = sh_println!(
+                "Compiling {} ({} build, {docker_tag})...",
+                info.package_name,
+                build_mode(options.docker)
+            );
+        }
+        let build_args = self
+            .build_args(info, options, &docker_tag, options.docker, &self.artifacts)
+            .map_err(eyre::Error::msg)?;
+        execute_build(&build_args, Some(info.path.clone())).map_err(|err| {
+            eyre::eyre!(
+                "Failed to build Rust contract '{}' at {}: {err}",
+                info.package_name,
+                info.path.display()
+            )
+        })?;
+
+        record_build_options(info, &self.artifacts, &docker_tag, options)?;
+        RustArtifact::load(info, &self.artifacts)
+    }
+
+    /// Returns the fluentbase [`BuildArgs`] building the crate in the given mode into `artifacts`.
+    fn build_args(
+        &self,
+        info: &RustProjectInfo,
+        options: &RustBuildOptions,
+        docker_tag: &str,
+        docker: bool,
+        artifacts: &Path,
+    ) -> Result<BuildArgs, String> {
+        let mut generate = options.fluent_artifacts()?;
+        // The reproducibility check compares the rWasm modules
+        if self.check_reproducible && !generate.iter().any(|a| matches!(a, FluentArtifact::Rwasm)) {
+            generate.push(FluentArtifact::Rwasm);
+        }
+        let build_args = BuildArgs {
+            contract_name: Some(info.artifact_name()),
+            generate,
+            features: options.features.clone(),
+            no_default_features: options.no_default_features,
+            docker,
+            docker_tag: docker_tag.to_string(),
+            mount_dir: Some(self.root.clone()),
+            output_path: Some(format!("{}/{{contract_name}}", artifacts.display())),
+            wasm_opt: options.wasm_opt,
+            locked: options.locked,
+            rust_version: Some(options.toolchain.clone()),
+            ..Default::default()
+        };
+        trace!(
+            target: "forge::compile",
+            package = %info.package_name,
+            ?build_args,
+            "building rust contract"
+        );
+        Ok(build_args)
+    }
+
+    /// Checks the crate with `cargo check` and returns its rustc diagnostics, see
+    /// [`check_crate`].
+    ///
+    /// Docker builds are checked on the host with the same pinned toolchain. If it isn't installed,
+    /// a warning explaining the missing diagnostics is returned instead.
+    fn check_crate(
+        &self,
+        info: &RustProjectInfo,
+        options: &RustBuildOptions,
+    ) -> Vec<RustWasmCompilationError> {
+        if options.docker && !is_toolchain_installed(&options.toolchain) {
+            return vec![RustWasmCompilationError {
+                severity: Severity::Warning,
+                ..RustWasmCompilationError::error(format!(
+                    "Rust contract '{}' was not checked, install the Rust toolchain {} with the \
+                     {WASM_TARGET} target to report its warnings and error locations",
+                    info.package_name, options.toolchain
+                ))
+            }];
+        }
+
+        check_crate(info, options, &self.root).unwrap_or_else(|err| {
+            warn!(
+                target: "forge::compile",
+                package = %info.package_name,
+                %err,
+                "failed to collect cargo diagnostics"
+            );
+            Vec::new()
+        })
+    }
+
+    /// Installs the pinned toolchain of a native build.
+    fn prepare_native_build(
+        &self,
+        info: &RustProjectInfo,
+        options: &RustBuildOptions,
+    ) -> Result<(), String> {
+        ensure_toolchain(&options.toolchain, self.quiet).map_err(|err| {
+            format!(
+                "Failed to install Rust toolchain {} for Rust contract '{}': {err:#}",
+                options.toolchain, info.package_name
+            )
+        })
+    }
+
+    /// Builds the crate again in the other mode, native or Docker, and compares the rWasm hash of
+    /// both builds.
+    fn verify_reproducible(
+        &self,
+        info: &RustProjectInfo,
+        options: &RustBuildOptions,
+        docker_tag: &str,
+    ) -> Result<(), String> {
+        let docker = !options.docker;
+        if !docker {
+            self.prepare_native_build(info, options)?;
+        }
+
+        // Must be below the project root, which is mounted into the build container
+        let check_root = self.artifacts.join(REPRODUCIBILITY_DIR);
+        let check_dir = info.artifact_dir(&check_root);
+        let build_args = self.build_args(info, options, docker_tag, docker, &check_root)?;
+        if !self.quiet {
+            let _ = sh_println!("Checking {} ({} build)...", info.package_name, build_mode(docker));
+        }
+        let result = execute_build(&build_args, Some(info.path.clone()))
+            .map_err(|err| {
+                format!(
+                    "Failed to build Rust contract '{}' for the reproducibility check: {err}",
+                    info.package_name
+                )
+            })
+            .and_then(|_| {
+                let hash = |root: &Path| {
+                    rwasm_hash(&info.rwasm_artifact_path(root)).map_err(|err| {
+                        format!(
+                            "Failed to check reproducibility of Rust contract '{}': {err:#}",
+                            info.package_name
+                        )
+                    })
+                };
+                let expected = hash(&self.artifacts)?;
+                let actual = hash(&check_root)?;
+                if expected != actual {
+                    return Err(format!(
+                        "Rust contract '{}' is not reproducible: the {} build produced rWasm hash \
+                         {expected}, the {} build {actual}",
+                        info.package_name,
+                        build_mode(options.docker),
+                        build_mode(docker),
+                    ));
+                }
+                Ok(())
+            });
+
+        let _ = fs::remove_dir_all(&check_dir);
+        let _ = fs::remove_dir(&check_root);
+        result
+    }
+
+    /// Builds the given crates on a pool of at most `jobs` threads.
+    ///
+    /// Each crate is given with the input path its artifact is attributed to, results are returned
+    /// in the same order.
+    fn compile_crates(
+        &self,
+        crates: &[(PathBuf, RustProjectInfo)],
+        settings: &RustWasmSettings,
+        cache: &RustBuildCache,
+    ) -> Vec<(Result<(Contract, B256), String>, Vec<RustWasmCompilationError>)> {
+        let build = |(_, info): &(PathBuf, RustProjectInfo)| {
+            let mut diagnostics = Vec::new();
+            let result = self.compile_crate(info, settings, cache, &mut diagnostics);
+            (result, diagnostics)
+        };
+        match rayon::ThreadPoolBuilder::new()
+            .num_threads(self.jobs)
+            .thread_name(|i| format!("rust-build-{i}"))
+            .build()
+        {
+            Ok(pool) => pool.install(|| crates.par_iter().map(build).collect()),
+            Err(err) => {
+                warn!(target: "forge::compile", %err, "failed to start rust build threads");
+                crates.iter().map(build).collect()
+            }
+        }
     }
 }
 
@@ -298,14 +796,10 @@ impl Compiler for RustWasmCompiler {
     type Language = RustWasmLanguage;
     type CompilerContract = Contract;
 
-    /// Returns the available version of the compiler. For Rust, this could be
-    /// the version of `cargo` or your internal builder. We'll hardcode it here.
     fn available_versions(&self, _language: &Self::Language) -> Vec<CompilerVersion> {
-        // This should reflect the version of your rust-wasm builder toolchain.
-        vec![CompilerVersion::Installed(Version::new(0, 1, 0))]
+        vec![CompilerVersion::Installed(RUST_WASM_COMPILER_VERSION)]
     }
 
-    /// The main compilation entrypoint.
     fn compile(
         &self,
         input: &Self::Input,
@@ -313,40 +807,64 @@ fn compile(
         CompilerOutput<Self::CompilationError, Self::CompilerContract>,
     > {
         let mut errors = Vec::new();
-        let contracts = BTreeMap::new();
-        let mut project_to_sources: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+        let mut contracts = BTreeMap::new();
 
-        // 1. Group source files by their Cargo project root.
+        // Group the dirty sources by crate, keeping the input path of the file the artifact is
+        // attributed to. The entry point is preferred, it is always part of the input if any file
+        // of the crate changed.
+        let mut crates: BTreeMap<PathBuf, PathBuf> = BTreeMap::new();
         for path in input.sources.keys() {
-            if let Some(root) = Self::find_project_root(path) {
-                project_to_sources.entry(root).or_default().push(path.clone());
-            } else {
-                errors.push(RustWasmCompilationError {
-                    message: format!(
-                        "Could not find Cargo.toml for source file: {}",
-                        path.display()
-                    ),
-                });
+            let Some(root) = Self::find_crate_root(&self.root.join(path)) else {
+                errors.push(RustWasmCompilationError::error(format!(
+                    "Could not find Cargo.toml for source file: {}",
+                    path.display()
+                )));
+                continue;
+            };
+            let is_entry = path.ends_with("src/lib.rs");
+            crates
+                .entry(root)
+                .and_modify(|source| {
+                    if is_entry {
+                        *source = path.clone();
+                    }
+                })
+                .or_insert_with(|| path.clone());
+        }
+
+        let mut infos = Vec::with_capacity(crates.len());
+        for (root, source) in crates {
+            match RustContractsRegistry::read_project_info(&root) {
+                Ok(info) => infos.push((source, info)),
+                Err(err) => errors.push(RustWasmCompilationError::error(format!(
+                    "Failed to read {}: {err}",
+                    root.join("Cargo.toml").display()
+                ))),
+            }
+        }
+
+        let mut cache = self.build_cache.as_deref().map(RustBuildCache::read).unwrap_or_default();
+        let results = self.compile_crates(&infos, &input.settings, &cache);
+        for ((source, info), (result, diagnostics)) in infos.into_iter().zip(results) {
+            errors.extend(diagnostics);
+            match result {
+                Ok((contract, fingerprint)) => {
+                    cache.crates.insert(info.package_name.clone(), fingerprint);
+                    contracts.entry(source).or_default().insert(info.package_name, contract);
+                }
+                Err(message) => {
+                    cache.crates.remove(&info.package_name);
+                    errors.push(RustWasmCompilationError::error(message));
+                }
             }
         }
 
-        // // 2. Compile each unique project once.
-        // for (project_path, source_files) in project_to_sources {
-        //     match Self::compile_rust_project(&project_path) {
-        //         Ok((contract_name, contract)) => {
-        //             // 3. Associate the compiled artifact with each source file from that
-        // project.             for source_path in source_files {
-        //                 let file_contracts:: &mut V = contracts.entry(source_path).or_default();
-        //                 file_contracts.insert(contract_name.clone(), contract.clone());
-        //             }
-        //         }
-        //         Err(err) => {
-        //             errors.push(RustWasmCompilationError { message: err });
-        //         }
-        //     }
-        // }
-
-        // 4. Construct the final output.
+        if let Some(path) = &self.build_cache
+            && let Err(err) = cache.write(path)
+        {
+            warn!(target: "forge::compile", %err, "failed to write rust build cache");
+        }
+
         Ok(CompilerOutput {
             errors,
             contracts,
@@ -355,3 +873,62 @@ fn compile(
         })
     }
 }
+
+/// Returns all `.rs` files of the crate at `root`, skipping build output.
+pub fn crate_sources(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root.join("src"))
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| e.file_name() != "target")
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file() && e.path().extension().is_some_and(|ext| ext == "rs"))
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// Loads the artifacts of a built crate into a [`Contract`], see [`RustArtifact::into_contract`].
+pub fn load_foundry_artifact(
+    info: &RustProjectInfo,
+    artifacts_root: &Path,
+) -> eyre::Result<Contract> {
+    RustArtifact::load(info, artifacts_root).map(RustArtifact::into_contract)
+}
+
+/// Records the build options of a crate in the `build_config` of its `metadata.json`, if the
+/// metadata artifact was generated.
+fn record_build_options(
+    info: &RustProjectInfo,
+    artifacts_root: &Path,
+    docker_tag: &str,
+    options: &RustBuildOptions,
+) -> eyre::Result<()> {
+    let path = info.artifact_dir(artifacts_root).join(METADATA_ARTIFACT_FILENAME);
+    if !path.is_file() {
+        return Ok(());
+    }
+
+    let mut metadata: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+    let Some(metadata) = metadata.as_object_mut() else {
+        eyre::bail!("{} is not a JSON object", path.display());
+    };
+    let build_config = metadata
+        .entry("build_config")
+        .or_insert_with(|| serde_json::Value::Object(Default::default()));
+    let Some(build_config) = build_config.as_object_mut() else {
+        eyre::bail!("`build_config` of {} is not a JSON object", path.display());
+    };
+
+    let serde_json::Value::Object(recorded) = serde_json::to_value(options)? else {
+        unreachable!("build options serialize to an object")
+    };
+    build_config.extend(recorded);
+    build_config.insert("docker_tag".to_string(), docker_tag.into());
+
+    fs::write(&path, serde_json::to_string_pretty(&metadata)?)?;
+    Ok(())
+}
+
+/// Returns the name of a build mode, for messages.
+fn build_mode(docker: bool) -> &'static str {
+    if docker { "Docker" } else { "native" }
+}
@@ -0,0 +1,157 @@
+//! Native (non-Docker) builds of Rust contracts.
+//!
+//! Docker builds run inside the fluentbase build image, which pins the Rust toolchain, the wasm
+//! target and the fluentbase builder. Native builds use the host instead, so the configured
+//! toolchain is installed through rustup and the builder version is checked against the one the
+//! image would use. [`rwasm_hash`] is used to compare the output of both build modes.
+
+use super::{RustWasmCompilationError, WASM_TARGET};
+use alloy_primitives::{B256, keccak256};
+use eyre::{Context, Result};
+use fluentbase_build::DEFAULT_DOCKER_TAG;
+use foundry_compilers::artifacts::Severity;
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::Path,
+    process::{Command, Stdio},
+    sync::{Mutex, PoisonError},
+};
+
+/// Toolchains known to be installed together with the wasm target.
+static READY_TOOLCHAINS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// Makes sure `toolchain` and its `wasm32-unknown-unknown` target are installed, installing them
+/// through rustup if necessary.
+///
+/// Concurrent builds wait for each other, so every toolchain is installed at most once.
+pub fn ensure_toolchain(toolchain: &str, quiet: bool) -> Result<()> {
+    let mut ready = READY_TOOLCHAINS.lock().unwrap_or_else(PoisonError::into_inner);
+    if ready.contains(toolchain) {
+        return Ok(());
+    }
+
+    let toolchains = rustup(&["toolchain", "list"])
+        .wrap_err("native Rust builds require rustup to pin the toolchain")?;
+    if !has_toolchain(&toolchains, toolchain) {
+        if !quiet {
+            let _ = sh_println!("Installing Rust toolchain {toolchain}...");
+        }
+        rustup(&[
+            "toolchain",
+            "install",
+            toolchain,
+            "--profile",
+            "minimal",
+            "--target",
+            WASM_TARGET,
+            "--no-self-update",
+        ])?;
+    } else {
+        let targets = rustup(&["target", "list", "--installed", "--toolchain", toolchain])?;
+        if !targets.lines().any(|target| target.trim() == WASM_TARGET) {
+            if !quiet {
+                let _ = sh_println!(
+                    "Installing {WASM_TARGET} target for Rust toolchain {toolchain}..."
+                );
+            }
+            rustup(&["target", "add", "--toolchain", toolchain, WASM_TARGET])?;
+        }
+    }
+
+    ready.insert(toolchain.to_string());
+    Ok(())
+}
+
+/// Returns true if `toolchain` and its `wasm32-unknown-unknown` target are installed, without
+/// installing them.
+pub fn is_toolchain_installed(toolchain: &str) -> bool {
+    let mut ready = READY_TOOLCHAINS.lock().unwrap_or_else(PoisonError::into_inner);
+    if ready.contains(toolchain) {
+        return true;
+    }
+
+    let installed = rustup(&["toolchain", "list"])
+        .is_ok_and(|toolchains| has_toolchain(&toolchains, toolchain))
+        && rustup(&["target", "list", "--installed", "--toolchain", toolchain])
+            .is_ok_and(|targets| targets.lines().any(|target| target.trim() == WASM_TARGET));
+    if installed {
+        ready.insert(toolchain.to_string());
+    }
+    installed
+}
+
+/// Returns a warning if the fluentbase build image `docker_tag` bundles a different builder than
+/// the one native builds use, in which case both builds may produce different bytecode.
+pub fn check_builder_version(package: &str, docker_tag: &str) -> Option<RustWasmCompilationError> {
+    let native = DEFAULT_DOCKER_TAG.trim_start_matches('v');
+    let image = docker_tag.trim_start_matches('v');
+    (native != image).then(|| RustWasmCompilationError {
+        severity: Severity::Warning,
+        ..RustWasmCompilationError::error(format!(
+            "Rust contract '{package}' targets fluentbase v{image}, but native builds use \
+             v{native}. The bytecode may differ from a Docker build, build with Docker to get \
+             verifiable artifacts."
+        ))
+    })
+}
+
+/// Returns the hash of the rWasm module at `path`.
+pub fn rwasm_hash(path: &Path) -> Result<B256> {
+    let code = fs::read(path).wrap_err_with(|| format!("failed to read {}", path.display()))?;
+    Ok(keccak256(code))
+}
+
+/// Runs rustup with the given arguments and returns its stdout.
+fn rustup(args: &[&str]) -> Result<String> {
+    trace!(target: "forge::compile", ?args, "running rustup");
+    let output = Command::new("rustup")
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .wrap_err("failed to run rustup, is it installed?")?;
+    if !output.status.success() {
+        eyre::bail!(
+            "`rustup {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Returns true if the `rustup toolchain list` output contains `toolchain`.
+///
+/// Toolchains are listed with their host triple, so `1.92.0` matches
+/// `1.92.0-x86_64-unknown-linux-gnu`.
+fn has_toolchain(list: &str, toolchain: &str) -> bool {
+    list.lines().filter_map(|line| line.split_whitespace().next()).any(|name| {
+        name == toolchain || name.strip_prefix(toolchain).is_some_and(|host| host.starts_with('-'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use foundry_compilers::CompilationError;
+
+    #[test]
+    fn finds_installed_toolchains() {
+        let list = "stable-x86_64-unknown-linux-gnu (default)\n\
+                    1.92.0-x86_64-unknown-linux-gnu\n\
+                    nightly-2025-01-01-x86_64-unknown-linux-gnu\n";
+
+        assert!(has_toolchain(list, "1.92.0-x86_64-unknown-linux-gnu"));
+        assert!(has_toolchain(list, "1.92.0"));
+        assert!(has_toolchain(list, "stable"));
+        assert!(!has_toolchain(list, "1.92"));
+        assert!(!has_toolchain(list, "1.91.0"));
+    }
+
+    #[test]
+    fn warns_on_builder_mismatch() {
+        assert!(check_builder_version("counter", DEFAULT_DOCKER_TAG).is_none());
+        let warning = check_builder_version("counter", "v0.0.1-unknown").unwrap();
+        assert!(warning.is_warning());
+    }
+}
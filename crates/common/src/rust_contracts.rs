@@ -1,10 +1,13 @@
-use eyre::Result;
+use crate::rust_artifact::{FOUNDRY_ARTIFACT_FILENAME, RWASM_ARTIFACT_FILENAME};
+use eyre::{Result, WrapErr};
+use foundry_config::Config;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
 use std::{
     collections::BTreeMap,
     fs,
     path::{Path, PathBuf},
 };
-use walkdir::WalkDir;
 
 #[derive(Debug, Clone)]
 pub struct RustProjectInfo {
@@ -30,7 +33,13 @@ pub fn artifact_dir(&self, artifacts_root: &Path) -> PathBuf {
     /// Get the foundry.json artifact path
     /// Examples: "out/erc20.wasm/foundry.json"
     pub fn foundry_artifact_path(&self, artifacts_root: &Path) -> PathBuf {
-        self.artifact_dir(artifacts_root).join("foundry.json")
+        self.artifact_dir(artifacts_root).join(FOUNDRY_ARTIFACT_FILENAME)
+    }
+
+    /// Get the compiled rWasm artifact path
+    /// Examples: "out/erc20.wasm/lib.rwasm"
+    pub fn rwasm_artifact_path(&self, artifacts_root: &Path) -> PathBuf {
+        self.artifact_dir(artifacts_root).join(RWASM_ARTIFACT_FILENAME)
     }
 }
 
@@ -41,51 +50,83 @@ pub struct RustContractsRegistry {
 }
 
 impl RustContractsRegistry {
+    /// Create a new registry by scanning a directory for Rust projects, see [`Self::with_filter`]
+    pub fn new(contract_dir: &Path, project_root: Option<&Path>) -> Result<Self> {
+        Self::with_filter(contract_dir, project_root, &RustContractsFilter::default())
+    }
+
     /// Create a new registry by scanning a directory for Rust projects
-    /// Recursively walks the directory and collects all Cargo.toml files
+    /// Recursively walks the directory and collects all contract packages selected by `filter`
+    ///
+    /// If a project root is given, files ignored by git are skipped: `.gitignore` files of the
+    /// scanned directories and their parents, `.git/info/exclude` and the global git excludes.
+    /// Build output (`target`) and hidden directories are always skipped.
+    ///
+    /// Packages are resolved like `cargo metadata` does: manifests are read together with the
+    /// workspace they belong to, so inherited fields resolve. Only `cdylib` packages depending on
+    /// `fluentbase-sdk` are contracts, workspace manifests and library crates are skipped.
     ///
     /// Arguments:
     /// - `contract_dir`: Directory to scan (typically `project.paths.sources`)
-    /// - `project_root`: Optional project root for gitignore support
-    pub fn new(contract_dir: &Path, project_root: Option<&Path>) -> Result<Self> {
-        let mut contracts = BTreeMap::new();
+    /// - `project_root`: Optional project root for gitignore support, `filter` globs are relative
+    ///   to it
+    /// - `filter`: Which crates to treat as contracts
+    ///
+    /// Fails if two contracts have the same package name.
+    pub fn with_filter(
+        contract_dir: &Path,
+        project_root: Option<&Path>,
+        filter: &RustContractsFilter,
+    ) -> Result<Self> {
+        let mut contracts: BTreeMap<String, RustProjectInfo> = BTreeMap::new();
 
         // Return empty registry if source root doesn't exist
         if !contract_dir.exists() || !contract_dir.is_dir() {
             return Ok(Self { contracts });
         }
 
-        // Load gitignore rules if project root is provided
-        let gitignore = project_root.and_then(|root| load_gitignore(root).ok()).unwrap_or_default();
-
-        // Walk through directory recursively
-        for entry in WalkDir::new(contract_dir)
-            .into_iter()
-            .filter_entry(|e| {
-                // Only filter directories, not files
-                if !e.file_type().is_dir() {
-                    return true;
-                }
-
-                // Check if this directory should be skipped
-                !should_skip_directory(e.path(), &gitignore, project_root)
-            })
-            .filter_map(Result::ok)
-        {
+        // Walk through directory recursively, respecting gitignore rules if project root is
+        // provided
+        let walker = WalkBuilder::new(contract_dir)
+            .standard_filters(project_root.is_some())
+            .hidden(true)
+            .require_git(false)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(|e| !is_build_output(e.path()))
+            .build();
+        let filter_root = project_root.unwrap_or(contract_dir);
+
+        for entry in walker.filter_map(Result::ok) {
             let path = entry.path();
 
             // Look for Cargo.toml files
             if path.is_file() && path.file_name() == Some(std::ffi::OsStr::new("Cargo.toml")) {
                 // Get the directory containing Cargo.toml (project root)
-                if let Some(project_dir) = path.parent() {
-                    match Self::read_project_info(project_dir) {
-                        Ok(info) => {
-                            contracts.insert(info.package_name.clone(), info);
-                        }
-                        Err(e) => {
-                            // Log error but continue scanning
-                            sh_warn!("Warning: Failed to read Cargo.toml at {path:?}: {e}")?;
+                let Some(project_dir) = path.parent() else { continue };
+                let relative = project_dir.strip_prefix(filter_root).unwrap_or(project_dir);
+                if !filter.is_match(relative) {
+                    trace!(path = %path.display(), "skipping manifest, excluded by filter");
+                    continue;
+                }
+                match Self::read_package(project_dir) {
+                    Ok(Some(info)) => {
+                        if let Some(existing) = contracts.get(&info.package_name) {
+                            eyre::bail!(
+                                "Found multiple Rust contracts named `{}`:\n  {}\n  {}\n\
+                                 Rename one of the packages, contract names must be unique.",
+                                info.package_name,
+                                existing.path.display(),
+                                info.path.display()
+                            );
                         }
+                        contracts.insert(info.package_name.clone(), info);
+                    }
+                    Ok(None) => {
+                        trace!(path = %path.display(), "skipping manifest, not a rust contract");
+                    }
+                    Err(e) => {
+                        // Log error but continue scanning
+                        sh_warn!("Warning: Failed to read Cargo.toml at {path:?}: {e}")?;
                     }
                 }
             }
@@ -94,37 +135,61 @@ pub fn new(contract_dir: &Path, project_root: Option<&Path>) -> Result<Self> {
         Ok(Self { contracts })
     }
 
-    /// Read project info from a directory containing Cargo.toml
-    fn read_project_info(project_dir: &Path) -> Result<RustProjectInfo> {
-        let cargo_toml_path = project_dir.join("Cargo.toml");
-        let cargo_content = fs::read_to_string(&cargo_toml_path)?;
-        let cargo_toml: toml::Value = toml::from_str(&cargo_content)?;
+    /// Read project info from a directory containing the Cargo.toml of a contract
+    pub(crate) fn read_project_info(project_dir: &Path) -> Result<RustProjectInfo> {
+        Self::read_package(project_dir)?.ok_or_else(|| {
+            eyre::eyre!(
+                "{} is not a Rust contract, expected a `cdylib` package depending on {SDK_PACKAGE}",
+                project_dir.join("Cargo.toml").display()
+            )
+        })
+    }
+
+    /// Reads the package at `project_dir`.
+    ///
+    /// Returns `None` if the manifest is not a contract: it has no `[package]`, is not a `cdylib`
+    /// or does not depend on `fluentbase-sdk`.
+    fn read_package(project_dir: &Path) -> Result<Option<RustProjectInfo>> {
+        let manifest = read_manifest(&project_dir.join("Cargo.toml"))?;
+        let Some(package) = manifest.get("package") else { return Ok(None) };
+        let workspace = Workspace::find(project_dir, package)?;
 
         // Extract package name (always kebab-case in Cargo.toml)
-        let package_name = cargo_toml
-            .get("package")
-            .and_then(|p| p.get("name"))
-            .and_then(|n| n.as_str())
-            .ok_or_else(|| eyre::eyre!("Package name not found in Cargo.toml"))?
-            .to_string();
+        let package_name = package
+            .get("name")
+            .and_then(|name| inherit(name, "package", "name", workspace.as_ref()))
+            .and_then(|n| n.as_str().map(str::to_string))
+            .ok_or_else(|| eyre::eyre!("Package name not found in Cargo.toml"))?;
+
+        let is_cdylib = manifest
+            .get("lib")
+            .and_then(|lib| lib.get("crate-type").or_else(|| lib.get("crate_type")))
+            .and_then(|types| types.as_array())
+            .is_some_and(|types| types.iter().any(|t| t.as_str() == Some("cdylib")));
+        if !is_cdylib || sdk_dependency(&manifest, workspace.as_ref()).is_none() {
+            return Ok(None);
+        }
 
         // Extract SDK version with multiple strategies
-        let sdk_version = Self::extract_sdk_version(&cargo_toml);
+        let sdk_version = Self::extract_sdk_version(&manifest, workspace.as_ref());
 
         let canonical_path =
             project_dir.canonicalize().unwrap_or_else(|_| project_dir.to_path_buf());
 
-        Ok(RustProjectInfo { path: canonical_path, package_name, sdk_version })
+        Ok(Some(RustProjectInfo { path: canonical_path, package_name, sdk_version }))
     }
 
-    /// Extract fluentbase-sdk version from dependencies
+    /// Extract fluentbase-sdk version from its dependency declaration
     /// Supports multiple formats:
     /// - fluentbase-sdk = "0.1.0"
     /// - fluentbase-sdk = { version = "0.1.0" }
     /// - fluentbase-sdk = { tag = "v0.1.0" }
-    fn extract_sdk_version(cargo_toml: &toml::Value) -> Option<String> {
-        let deps = cargo_toml.get("dependencies")?;
-        let sdk = deps.get("fluentbase-sdk")?;
+    /// - fluentbase-sdk = { workspace = true }, resolved from `[workspace.dependencies]`
+    fn extract_sdk_version(
+        cargo_toml: &toml::Value,
+        workspace: Option<&Workspace>,
+    ) -> Option<String> {
+        let sdk = sdk_dependency(cargo_toml, workspace)?;
 
         // Try direct string version first
         if let Some(version_str) = sdk.as_str() {
@@ -152,9 +217,10 @@ fn extract_sdk_version(cargo_toml: &toml::Value) -> Option<String> {
     /// - Package name: "erc20", "power-calculator"
     /// - With extension: "erc20.wasm", "power-calculator.wasm"
     /// - Case insensitive: "ERC20", "POWER-CALCULATOR"
+    /// - Without separators: "PowerCalculator.wasm", "power_calculator"
     pub fn get(&self, name: &str) -> Option<&RustProjectInfo> {
-        let normalized = normalize_contract_name(name);
-        self.contracts.get(&normalized)
+        let package = find_package_name(name, self.contracts.keys().map(String::as_str))?;
+        self.contracts.get(package)
     }
 
     /// Check if registry contains a contract with given name
@@ -216,99 +282,194 @@ pub fn normalize_contract_name(name: &str) -> String {
     base_name.to_lowercase()
 }
 
-/// Check if directory should be skipped during scanning
-fn should_skip_directory(
-    dir: &Path,
-    gitignore: &GitignoreRules,
-    project_root: Option<&Path>,
-) -> bool {
-    // Standard directories to skip
-    if let Some(name) = dir.file_name().and_then(|n| n.to_str()) {
-        if matches!(
-            name,
-            "target" | ".git" | "node_modules" | ".idea" | ".vscode" | "dist" | "build" | "lib"
-        ) {
-            return true;
+/// Finds the package `name` refers to among `packages`, following the rules of
+/// [`RustContractsRegistry::get`].
+///
+/// Exact matches of the normalized name take precedence over matches without separators.
+pub fn find_package_name<'a>(
+    name: &str,
+    packages: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let normalized = normalize_contract_name(name);
+    let stripped = strip_separators(&normalized);
+    let mut fallback = None;
+    for package in packages {
+        if package == normalized {
+            return Some(package);
         }
-
-        // Skip hidden directories
-        if name.starts_with('.') {
-            return true;
+        if fallback.is_none() && strip_separators(package) == stripped {
+            fallback = Some(package);
         }
     }
+    fallback
+}
 
-    // Check gitignore rules
-    if let Some(root) = project_root
-        && let Ok(relative) = dir.strip_prefix(root)
-    {
-        return gitignore.should_ignore(relative);
-    }
-
-    false
+/// Removes `-` and `_` from a package name, so `power-calculator` matches `PowerCalculator`.
+fn strip_separators(name: &str) -> String {
+    name.chars().filter(|c| !matches!(c, '-' | '_')).collect()
 }
 
-#[derive(Default)]
-struct GitignoreRules {
-    patterns: Vec<String>,
+/// Name of the SDK package every contract depends on.
+const SDK_PACKAGE: &str = "fluentbase-sdk";
+
+/// A Cargo workspace root manifest.
+struct Workspace {
+    root: PathBuf,
+    /// The `[workspace]` table.
+    table: toml::Value,
 }
 
-impl GitignoreRules {
-    fn should_ignore(&self, path: &Path) -> bool {
-        let path_str = path.to_string_lossy();
-
-        self.patterns.iter().any(|pattern| {
-            if let Some(stripped) = pattern.strip_prefix('/') {
-                path_str.starts_with(stripped)
-            } else if pattern.ends_with('/') {
-                path.is_dir() && path_str.contains(&pattern[..pattern.len() - 1])
-            } else {
-                path.components().any(|component| {
-                    component
-                        .as_os_str()
-                        .to_str()
-                        .is_some_and(|name| name == pattern || matches_simple_glob(name, pattern))
-                })
+impl Workspace {
+    /// Finds the workspace the package at `package_dir` is a member of, like Cargo does.
+    ///
+    /// This is the workspace the `package.workspace` key points to, or the first ancestor
+    /// manifest with a `[workspace]` table.
+    fn find(package_dir: &Path, package: &toml::Value) -> Result<Option<Self>> {
+        if let Some(path) = package.get("workspace").and_then(|w| w.as_str()) {
+            let root = package_dir.join(path);
+            let manifest = read_manifest(&root.join("Cargo.toml"))?;
+            let table = manifest
+                .get("workspace")
+                .cloned()
+                .ok_or_else(|| eyre::eyre!("{} is not a workspace", root.display()))?;
+            return Ok(Some(Self { root, table }));
+        }
+
+        for root in package_dir.ancestors() {
+            let manifest_path = root.join("Cargo.toml");
+            if !manifest_path.is_file() {
+                continue;
             }
-        })
+            let Some(table) = read_manifest(&manifest_path)?.get("workspace").cloned() else {
+                continue;
+            };
+            let workspace = Self { root: root.to_path_buf(), table };
+            return Ok(workspace.has_member(package_dir).then_some(workspace));
+        }
+
+        Ok(None)
+    }
+
+    /// Returns true if the package at `package_dir` is a member of this workspace.
+    ///
+    /// Like Cargo, the package must match one of the `members` globs exactly and must not be
+    /// inside one of the `exclude` paths.
+    fn has_member(&self, package_dir: &Path) -> bool {
+        let Ok(relative) = package_dir.strip_prefix(&self.root) else { return false };
+        if relative.as_os_str().is_empty() {
+            return true;
+        }
+
+        let paths = |key: &str| {
+            self.table
+                .get(key)
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|p| p.as_str())
+                .map(|p| p.trim_start_matches("./").trim_end_matches('/'))
+        };
+        let is_member = paths("members").any(|pattern| {
+            GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .is_ok_and(|glob| glob.compile_matcher().is_match(relative))
+        });
+        is_member && !paths("exclude").any(|path| relative.starts_with(path))
     }
 }
 
-/// Load gitignore rules from file
-fn load_gitignore(project_root: &Path) -> eyre::Result<GitignoreRules> {
-    let gitignore_path = project_root.join(".gitignore");
+/// Resolves `value` if it is inherited from the workspace (`{ workspace = true }`).
+///
+/// Inherited values are looked up in `[workspace.<section>]`, e.g. `[workspace.package]` or
+/// `[workspace.dependencies]`. Keys set next to `workspace = true` extend the inherited table, like
+/// `features` of a dependency.
+fn inherit(
+    value: &toml::Value,
+    section: &str,
+    key: &str,
+    workspace: Option<&Workspace>,
+) -> Option<toml::Value> {
+    let is_inherited = value.get("workspace").and_then(|w| w.as_bool()).unwrap_or(false);
+    if !is_inherited {
+        return Some(value.clone());
+    }
 
-    if !gitignore_path.exists() {
-        return Ok(GitignoreRules::default());
+    let inherited = workspace?.table.get(section)?.get(key)?.clone();
+    match (inherited, value.as_table()) {
+        (toml::Value::Table(mut inherited), Some(overrides)) => {
+            for (k, v) in overrides.iter().filter(|(k, _)| *k != "workspace") {
+                inherited.insert(k.clone(), v.clone());
+            }
+            Some(toml::Value::Table(inherited))
+        }
+        (inherited, _) => Some(inherited),
     }
+}
 
-    let content = std::fs::read_to_string(gitignore_path)?;
-    let patterns: Vec<String> = content
-        .lines()
-        .filter(|line| !line.trim().is_empty() && !line.trim().starts_with('#'))
-        .map(|line| line.trim().to_string())
-        .collect();
+/// Returns the resolved `fluentbase-sdk` dependency declaration of a manifest, also if the
+/// dependency is renamed.
+fn sdk_dependency(manifest: &toml::Value, workspace: Option<&Workspace>) -> Option<toml::Value> {
+    manifest.get("dependencies")?.as_table()?.iter().find_map(|(key, dep)| {
+        let dep = inherit(dep, "dependencies", key, workspace)?;
+        let package = dep.get("package").and_then(|p| p.as_str()).unwrap_or(key);
+        (package == SDK_PACKAGE).then_some(dep)
+    })
+}
 
-    Ok(GitignoreRules { patterns })
+/// Reads and parses a `Cargo.toml`.
+fn read_manifest(path: &Path) -> Result<toml::Value> {
+    let content = fs::read_to_string(path)?;
+    Ok(toml::from_str(&content)?)
 }
 
-/// Simple glob pattern matching
-fn matches_simple_glob(text: &str, pattern: &str) -> bool {
-    match (pattern.starts_with('*'), pattern.ends_with('*')) {
-        (true, true) => {
-            let middle = &pattern[1..pattern.len() - 1];
-            text.contains(middle)
-        }
-        (true, false) => {
-            let suffix = &pattern[1..];
-            text.ends_with(suffix)
-        }
-        (false, true) => {
-            let prefix = &pattern[..pattern.len() - 1];
-            text.starts_with(prefix)
-        }
-        (false, false) => text == pattern,
+/// Returns true for directories that never contain contracts: Cargo and npm build output.
+fn is_build_output(dir: &Path) -> bool {
+    dir.file_name().is_some_and(|name| name == "target" || name == "node_modules")
+}
+
+/// Include and exclude globs selecting which crates are treated as contracts.
+///
+/// Globs are matched against crate directories relative to the project root. A crate is a contract
+/// if it matches any include glob, or there are none, and matches no exclude glob.
+#[derive(Clone, Debug, Default)]
+pub struct RustContractsFilter {
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+}
+
+impl RustContractsFilter {
+    /// Creates a new filter from include and exclude globs, e.g. `src/contracts/*` or
+    /// `src/**/legacy`.
+    pub fn new(include: &[String], exclude: &[String]) -> Result<Self> {
+        let glob_set = |globs: &[String]| -> Result<Option<GlobSet>> {
+            if globs.is_empty() {
+                return Ok(None);
+            }
+            let mut builder = GlobSetBuilder::new();
+            for glob in globs {
+                let glob = glob.trim_start_matches("./").trim_end_matches('/');
+                builder.add(GlobBuilder::new(glob).literal_separator(true).build()?);
+            }
+            Ok(Some(builder.build()?))
+        };
+        Ok(Self { include: glob_set(include)?, exclude: glob_set(exclude)? })
+    }
+
+    /// Returns the filter selecting the Rust contracts of the project, taken from the
+    /// `[rust.contracts]` section of the [`Config`].
+    pub fn from_config(config: &Config) -> Result<Self> {
+        Self::new(&config.rust.contracts.include, &config.rust.contracts.exclude)
+            .wrap_err("invalid `rust.contracts` glob")
+    }
+
+    /// Returns true if the crate at `relative` is selected.
+    pub fn is_match(&self, relative: &Path) -> bool {
+        self.include.as_ref().is_none_or(|include| include.is_match(relative))
+            && !self.exclude.as_ref().is_some_and(|exclude| exclude.is_match(relative))
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -331,6 +492,15 @@ fn test_normalize_contract_name() {
         assert_eq!(normalize_contract_name("Power-Calculator.wasm"), "power-calculator");
     }
 
+    #[test]
+    fn test_find_package_name() {
+        let packages = ["power-calculator", "powercalculator", "erc20"];
+        assert_eq!(find_package_name("PowerCalculator.wasm", packages), Some("powercalculator"));
+        assert_eq!(find_package_name("power_calculator", packages), Some("power-calculator"));
+        assert_eq!(find_package_name("ERC20", packages), Some("erc20"));
+        assert_eq!(find_package_name("Counter.wasm", packages), None);
+    }
+
     #[test]
     fn test_sdk_version_extraction() {
         // Test string format
@@ -339,7 +509,10 @@ fn test_sdk_version_extraction() {
 fluentbase-sdk = "0.1.0"
 "#;
         let value: toml::Value = toml::from_str(toml_str).unwrap();
-        assert_eq!(RustContractsRegistry::extract_sdk_version(&value), Some("0.1.0".to_string()));
+        assert_eq!(
+            RustContractsRegistry::extract_sdk_version(&value, None),
+            Some("0.1.0".to_string())
+        );
 
         // Test version in table
         let toml_table = r#"
@@ -347,7 +520,10 @@ fn test_sdk_version_extraction() {
 fluentbase-sdk = { version = "0.2.0" }
 "#;
         let value: toml::Value = toml::from_str(toml_table).unwrap();
-        assert_eq!(RustContractsRegistry::extract_sdk_version(&value), Some("0.2.0".to_string()));
+        assert_eq!(
+            RustContractsRegistry::extract_sdk_version(&value, None),
+            Some("0.2.0".to_string())
+        );
 
         // Test tag in table
         let toml_tag = r#"
@@ -355,7 +531,10 @@ fn test_sdk_version_extraction() {
 fluentbase-sdk = { tag = "v0.3.0" }
 "#;
         let value: toml::Value = toml::from_str(toml_tag).unwrap();
-        assert_eq!(RustContractsRegistry::extract_sdk_version(&value), Some("v0.3.0".to_string()));
+        assert_eq!(
+            RustContractsRegistry::extract_sdk_version(&value, None),
+            Some("v0.3.0".to_string())
+        );
     }
 
     #[test]
@@ -381,6 +560,9 @@ fn test_blended_structure() {
 name = "power-calculator"
 version = "0.1.0"
 
+[lib]
+crate-type = ["cdylib", "staticlib"]
+
 [dependencies]
 fluentbase-sdk = { version = "0.1.0" }
 "#,
@@ -395,6 +577,12 @@ fn test_blended_structure() {
             r#"[package]
 name = "erc20"
 version = "0.1.0"
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+sdk = { package = "fluentbase-sdk", tag = "v0.2.0" }
 "#,
         )
         .unwrap();
@@ -412,10 +600,127 @@ fn test_blended_structure() {
         assert!(registry.contains("power-calculator"));
         assert!(registry.contains("power-calculator.wasm"));
         assert!(registry.contains("POWER-CALCULATOR"));
+        assert!(registry.contains("PowerCalculator.wasm"));
+        assert!(registry.contains("power_calculator"));
+        assert!(!registry.contains("power"));
 
         // Test erc20
         assert!(registry.contains("erc20"));
         assert!(registry.contains("ERC20.wasm"));
+        assert_eq!(registry.get("erc20").unwrap().sdk_version, Some("v0.2.0".to_string()));
+    }
+
+    #[test]
+    fn test_workspace_structure() {
+        let temp_dir = TempDir::new().unwrap();
+        let src_dir = temp_dir.path().join("src");
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            src_dir.join("Cargo.toml"),
+            r#"[workspace]
+members = ["contracts/*", "utils"]
+exclude = ["contracts/excluded"]
+
+[workspace.package]
+version = "0.1.0"
+
+[workspace.dependencies]
+fluentbase-sdk = { git = "https://github.com/fluentlabs-xyz/fluentbase", tag = "v1.2.0", default-features = false }
+"#,
+        )
+        .unwrap();
+
+        let write_package = |dir: &str, manifest: &str| {
+            let dir = src_dir.join(dir);
+            fs::create_dir_all(dir.join("src")).unwrap();
+            fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+        };
+        write_package(
+            "contracts/counter",
+            r#"[package]
+name = "counter"
+version.workspace = true
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+fluentbase-sdk = { workspace = true, features = ["std"] }
+"#,
+        );
+        // Library crate, not a contract
+        write_package(
+            "utils",
+            r#"[package]
+name = "utils"
+version.workspace = true
+
+[dependencies]
+fluentbase-sdk.workspace = true
+"#,
+        );
+        // Not a workspace member, the inherited SDK doesn't resolve
+        write_package(
+            "contracts/excluded",
+            r#"[package]
+name = "excluded"
+version = "0.1.0"
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+fluentbase-sdk = { workspace = true }
+"#,
+        );
+
+        // Nested in a member directory, but not a member itself
+        write_package(
+            "utils/nested",
+            r#"[package]
+name = "nested"
+version = "0.1.0"
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+fluentbase-sdk = { workspace = true }
+"#,
+        );
+
+        let registry = RustContractsRegistry::new(&src_dir, Some(temp_dir.path())).unwrap();
+        assert_eq!(registry.package_names().collect::<Vec<_>>(), ["counter"]);
+        assert_eq!(registry.get("counter").unwrap().sdk_version, Some("v1.2.0".to_string()));
+    }
+
+    #[test]
+    fn test_duplicate_package_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest = r#"[package]
+name = "counter"
+version = "0.1.0"
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+fluentbase-sdk = "0.1.0"
+"#;
+        for dir in ["a", "b"] {
+            let dir = temp_dir.path().join(dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+        }
+
+        let err = RustContractsRegistry::new(temp_dir.path(), None).unwrap_err().to_string();
+        assert!(err.contains("multiple Rust contracts named `counter`"), "{err}");
+        assert!(
+            err.contains(&temp_dir.path().join("a").canonicalize().unwrap().display().to_string())
+        );
+        assert!(
+            err.contains(&temp_dir.path().join("b").canonicalize().unwrap().display().to_string())
+        );
     }
 
     #[test]
@@ -441,28 +746,80 @@ fn test_artifact_paths() {
         );
     }
 
-    #[test]
-    fn test_should_skip_directory() {
-        let gitignore =
-            GitignoreRules { patterns: vec!["target/".to_string(), "*.log".to_string()] };
+    fn write_contract(dir: &Path, name: &str) {
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            format!(
+                r#"[package]
+name = "{name}"
+version = "0.1.0"
 
-        // Standard directories should be skipped
-        assert!(should_skip_directory(Path::new("target"), &gitignore, None));
-        assert!(should_skip_directory(Path::new(".git"), &gitignore, None));
-        assert!(should_skip_directory(Path::new("node_modules"), &gitignore, None));
-        assert!(should_skip_directory(Path::new(".hidden"), &gitignore, None));
+[lib]
+crate-type = ["cdylib"]
 
-        // Regular directories should not be skipped
-        assert!(!should_skip_directory(Path::new("src"), &gitignore, None));
-        assert!(!should_skip_directory(Path::new("tests"), &gitignore, None));
+[dependencies]
+fluentbase-sdk = "0.1.0"
+"#
+            ),
+        )
+        .unwrap();
     }
 
     #[test]
-    fn test_simple_glob() {
-        assert!(matches_simple_glob("test.log", "*.log"));
-        assert!(matches_simple_glob("test", "test*"));
-        assert!(matches_simple_glob("mytest", "*test"));
-        assert!(matches_simple_glob("mytestfile", "*test*"));
-        assert!(!matches_simple_glob("test", "*.log"));
+    fn test_gitignore_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let src_dir = root.join("src");
+
+        fs::write(root.join(".gitignore"), "/src/generated/**\n!/src/generated/kept\n").unwrap();
+        fs::create_dir_all(src_dir.join("nested")).unwrap();
+        fs::write(src_dir.join("nested/.gitignore"), "scratch-*\n").unwrap();
+
+        write_contract(&src_dir.join("generated/dropped"), "dropped");
+        write_contract(&src_dir.join("generated/kept"), "kept");
+        write_contract(&src_dir.join("nested/scratch-counter"), "scratch");
+        write_contract(&src_dir.join("nested/counter"), "counter");
+        // Only build output and hidden directories are skipped by name
+        write_contract(&src_dir.join("lib/erc20"), "erc20");
+        write_contract(&src_dir.join("counter/target/package"), "packaged");
+        write_contract(&src_dir.join(".cache/counter"), "cached");
+
+        let registry = RustContractsRegistry::new(&src_dir, Some(root)).unwrap();
+        assert_eq!(registry.package_names().collect::<Vec<_>>(), ["counter", "erc20", "kept"]);
+
+        // Without a project root gitignore files are not applied
+        let registry = RustContractsRegistry::new(&src_dir, None).unwrap();
+        assert_eq!(
+            registry.package_names().collect::<Vec<_>>(),
+            ["counter", "dropped", "erc20", "kept", "scratch"]
+        );
+    }
+
+    #[test]
+    fn test_contracts_filter() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let src_dir = root.join("src");
+
+        write_contract(&src_dir.join("contracts/counter"), "counter");
+        write_contract(&src_dir.join("contracts/legacy/erc20"), "erc20");
+        write_contract(&src_dir.join("examples/greeting"), "greeting");
+
+        let filter = RustContractsFilter::new(
+            &["src/contracts/**".to_string()],
+            &["**/legacy/*".to_string()],
+        )
+        .unwrap();
+        let registry = RustContractsRegistry::with_filter(&src_dir, Some(root), &filter).unwrap();
+        assert_eq!(registry.package_names().collect::<Vec<_>>(), ["counter"]);
+
+        // `*` doesn't match across directories
+        let filter = RustContractsFilter::new(&["./src/*".to_string()], &[]).unwrap();
+        assert!(!filter.is_match(Path::new("src/contracts/counter")));
+        let filter = RustContractsFilter::new(&["src/*/*".to_string()], &[]).unwrap();
+        assert!(filter.is_match(Path::new("src/contracts/counter")));
+
+        assert!(RustContractsFilter::new(&["src/[".to_string()], &[]).is_err());
     }
 }
@@ -0,0 +1,462 @@
+//! Structure of wasm modules, used to inspect Rust contracts.
+
+use alloy_primitives::Bytes;
+use eyre::Result;
+use serde::Serialize;
+use std::{borrow::Cow, fmt};
+use wasm_encoder::{CustomSection, Module, RawSection};
+use wasmparser::{CompositeInnerType, ExternalKind, FuncType, Parser, Payload, TypeRef, ValType};
+
+/// Magic bytes every wasm module starts with.
+pub const WASM_MAGIC: [u8; 4] = *b"\0asm";
+
+/// Magic bytes every rWasm module starts with.
+pub const RWASM_MAGIC: [u8; 2] = [0xef, 0x52];
+
+/// Maximum size in bytes of a wasm module deployed to Fluent, the wasm module is the initcode of
+/// the deployment.
+pub use fluentbase_types::WASM_MAX_CODE_SIZE;
+
+/// Size in bytes above which the rWasm module a wasm module is translated to on Fluent, the
+/// deployed code, is reported as oversized.
+///
+/// Fluent doesn't define a limit for rWasm modules, so this reuses the wasm limit and is only
+/// checked as a warning, see [`check_rwasm_size`].
+pub const RWASM_MAX_CODE_SIZE: usize = WASM_MAX_CODE_SIZE;
+
+/// Returns true if `code` is a wasm module.
+pub fn is_wasm(code: &[u8]) -> bool {
+    code.starts_with(&WASM_MAGIC)
+}
+
+/// Returns true if `code` is an rWasm module, i.e. the deployed code of a Rust contract.
+pub fn is_rwasm(code: &[u8]) -> bool {
+    code.starts_with(&RWASM_MAGIC)
+}
+
+/// Checks the size of the wasm module against Fluent's limit, [`WASM_MAX_CODE_SIZE`].
+pub fn check_code_size(name: &str, wasm_size: usize) -> Result<()> {
+    eyre::ensure!(
+        wasm_size <= WASM_MAX_CODE_SIZE,
+        "`{name}` is above the wasm size limit ({wasm_size} > {WASM_MAX_CODE_SIZE})"
+    );
+    Ok(())
+}
+
+/// Returns a warning if the rWasm module is above [`RWASM_MAX_CODE_SIZE`].
+pub fn check_rwasm_size(name: &str, rwasm_size: usize) -> Option<String> {
+    (rwasm_size > RWASM_MAX_CODE_SIZE).then(|| {
+        format!("`{name}` is above the rWasm size limit ({rwasm_size} > {RWASM_MAX_CODE_SIZE})")
+    })
+}
+
+/// Name of the custom section holding the ABI-encoded constructor arguments of a wasm deployment.
+pub const CONSTRUCTOR_INPUT_SECTION: &str = "input";
+
+/// Returns the initcode deploying the wasm module `wasm` with the ABI-encoded `constructor_args`.
+///
+/// Unlike EVM initcode, the arguments aren't appended to the code but added as the
+/// [`CONSTRUCTOR_INPUT_SECTION`] custom section.
+pub fn with_constructor_args(wasm: &[u8], constructor_args: &[u8]) -> Bytes {
+    let mut module = Module::new();
+    let sections = Parser::new(0).parse_all(wasm).flatten();
+    for (id, range) in sections.filter_map(|payload| payload.as_section()) {
+        module.section(&RawSection { id, data: &wasm[range] });
+    }
+    module.section(&CustomSection {
+        name: Cow::Borrowed(CONSTRUCTOR_INPUT_SECTION),
+        data: Cow::Borrowed(constructor_args),
+    });
+    Bytes::from(module.finish())
+}
+
+/// Returns the ABI-encoded constructor arguments of the initcode of a wasm deployment, or `None`
+/// if it has no [`CONSTRUCTOR_INPUT_SECTION`], see [`with_constructor_args`].
+pub fn constructor_args(initcode: &[u8]) -> Result<Option<Bytes>> {
+    for payload in Parser::new(0).parse_all(initcode) {
+        if let Payload::CustomSection(reader) = payload?
+            && reader.name() == CONSTRUCTOR_INPUT_SECTION
+        {
+            return Ok(Some(Bytes::copy_from_slice(reader.data())));
+        }
+    }
+    Ok(None)
+}
+
+/// Names of the custom sections only holding metadata of a wasm module, such as the names of its
+/// functions or the tools it was produced by.
+pub const METADATA_SECTIONS: [&str; 2] = ["name", "producers"];
+
+/// Returns the wasm module `code` without its [`METADATA_SECTIONS`].
+///
+/// These sections don't affect the execution of the module, unlike the other custom sections such
+/// as the constructor arguments.
+pub fn without_metadata_sections(code: &[u8]) -> Result<Bytes> {
+    let mut module = Module::new();
+    for payload in Parser::new(0).parse_all(code) {
+        let payload = payload?;
+        if let Payload::CustomSection(reader) = &payload
+            && METADATA_SECTIONS.contains(&reader.name())
+        {
+            continue;
+        }
+        if let Some((id, range)) = payload.as_section() {
+            module.section(&RawSection { id, data: &code[range] });
+        }
+    }
+    Ok(Bytes::from(module.finish()))
+}
+
+/// The imports, exports, limits and sections of a wasm module.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct WasmModuleInfo {
+    /// Imported items, for contracts these are the host functions of the runtime.
+    pub imports: Vec<WasmImport>,
+    /// Exported items.
+    pub exports: Vec<WasmExport>,
+    /// Limits of the declared and imported memories, in 64 KiB pages.
+    pub memories: Vec<WasmLimits>,
+    /// Limits of the declared and imported tables, in elements.
+    pub tables: Vec<WasmLimits>,
+    /// Sections in module order.
+    pub sections: Vec<WasmSection>,
+}
+
+/// An imported item of a wasm module.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WasmImport {
+    pub module: String,
+    pub name: String,
+    pub kind: WasmItemKind,
+    /// The signature of imported functions, e.g. `(i32, i32) -> i64`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// An exported item of a wasm module.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WasmExport {
+    pub name: String,
+    pub kind: WasmItemKind,
+    /// The signature of exported functions, e.g. `() -> ()`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// The kind of an imported or exported item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WasmItemKind {
+    Function,
+    Table,
+    Memory,
+    Global,
+    Tag,
+}
+
+impl fmt::Display for WasmItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Function => "function",
+            Self::Table => "table",
+            Self::Memory => "memory",
+            Self::Global => "global",
+            Self::Tag => "tag",
+        })
+    }
+}
+
+/// Initial and maximum size of a memory or table.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WasmLimits {
+    /// Whether the memory or table is imported.
+    pub imported: bool,
+    pub initial: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<u64>,
+}
+
+/// A section of a wasm module.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WasmSection {
+    /// The section name, custom sections are named `custom:<name>`.
+    pub name: String,
+    /// Size of the section payload in bytes.
+    pub size: usize,
+}
+
+impl WasmModuleInfo {
+    /// Parses the structure of the wasm module `code`.
+    pub fn parse(code: &[u8]) -> Result<Self> {
+        let mut info = Self::default();
+        let mut types: Vec<Option<FuncType>> = Vec::new();
+        // Type index of every function, imported functions come first
+        let mut functions: Vec<u32> = Vec::new();
+
+        for payload in Parser::new(0).parse_all(code) {
+            let payload = payload?;
+            if let Some((id, range)) = payload.as_section() {
+                let name = match &payload {
+                    Payload::CustomSection(reader) => format!("custom:{}", reader.name()),
+                    _ => section_name(id).to_string(),
+                };
+                info.sections.push(WasmSection { name, size: range.len() });
+            }
+
+            match payload {
+                Payload::TypeSection(reader) => {
+                    for rec_group in reader {
+                        types.extend(rec_group?.into_types().map(
+                            |ty| match ty.composite_type.inner {
+                                CompositeInnerType::Func(func) => Some(func),
+                                _ => None,
+                            },
+                        ));
+                    }
+                }
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import?;
+                        let (kind, signature) = match import.ty {
+                            TypeRef::Func(ty) => {
+                                functions.push(ty);
+                                (WasmItemKind::Function, signature(&types, ty))
+                            }
+                            TypeRef::Table(table) => {
+                                info.tables.push(WasmLimits {
+                                    imported: true,
+                                    initial: table.initial,
+                                    maximum: table.maximum,
+                                });
+                                (WasmItemKind::Table, None)
+                            }
+                            TypeRef::Memory(memory) => {
+                                info.memories.push(WasmLimits {
+                                    imported: true,
+                                    initial: memory.initial,
+                                    maximum: memory.maximum,
+                                });
+                                (WasmItemKind::Memory, None)
+                            }
+                            TypeRef::Global(_) => (WasmItemKind::Global, None),
+                            _ => (WasmItemKind::Tag, None),
+                        };
+                        info.imports.push(WasmImport {
+                            module: import.module.to_string(),
+                            name: import.name.to_string(),
+                            kind,
+                            signature,
+                        });
+                    }
+                }
+                Payload::FunctionSection(reader) => {
+                    for ty in reader {
+                        functions.push(ty?);
+                    }
+                }
+                Payload::TableSection(reader) => {
+                    for table in reader {
+                        let table = table?;
+                        info.tables.push(WasmLimits {
+                            imported: false,
+                            initial: table.ty.initial,
+                            maximum: table.ty.maximum,
+                        });
+                    }
+                }
+                Payload::MemorySection(reader) => {
+                    for memory in reader {
+                        let memory = memory?;
+                        info.memories.push(WasmLimits {
+                            imported: false,
+                            initial: memory.initial,
+                            maximum: memory.maximum,
+                        });
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export?;
+                        let (kind, signature) = match export.kind {
+                            ExternalKind::Func => (
+                                WasmItemKind::Function,
+                                functions
+                                    .get(export.index as usize)
+                                    .and_then(|&ty| signature(&types, ty)),
+                            ),
+                            ExternalKind::Table => (WasmItemKind::Table, None),
+                            ExternalKind::Memory => (WasmItemKind::Memory, None),
+                            ExternalKind::Global => (WasmItemKind::Global, None),
+                            _ => (WasmItemKind::Tag, None),
+                        };
+                        info.exports.push(WasmExport {
+                            name: export.name.to_string(),
+                            kind,
+                            signature,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+
+    /// Returns the imported functions.
+    pub fn imported_functions(&self) -> impl Iterator<Item = &WasmImport> {
+        self.imports.iter().filter(|import| import.kind == WasmItemKind::Function)
+    }
+
+    /// Returns the exported functions.
+    pub fn exported_functions(&self) -> impl Iterator<Item = &WasmExport> {
+        self.exports.iter().filter(|export| export.kind == WasmItemKind::Function)
+    }
+}
+
+/// Returns the signature of the function type at `index`, e.g. `(i32, i32) -> i64`.
+fn signature(types: &[Option<FuncType>], index: u32) -> Option<String> {
+    let func = types.get(index as usize)?.as_ref()?;
+    let list =
+        |types: &[ValType]| types.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+    Some(format!("({}) -> ({})", list(func.params()), list(func.results())))
+}
+
+/// Returns the name of the section with the given id.
+fn section_name(id: u8) -> &'static str {
+    match id {
+        0 => "custom",
+        1 => "type",
+        2 => "import",
+        3 => "function",
+        4 => "table",
+        5 => "memory",
+        6 => "global",
+        7 => "export",
+        8 => "start",
+        9 => "element",
+        10 => "code",
+        11 => "data",
+        12 => "datacount",
+        13 => "tag",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_module() {
+        // (module
+        //   (type (func (param i32 i32)))
+        //   (type (func))
+        //   (import "fluentbase_v1preview" "_write" (func (type 0)))
+        //   (func (type 1))
+        //   (memory 1 16)
+        //   (export "main" (func 1))
+        //   (export "memory" (memory 0)))
+        let code = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x09, 0x02, 0x60, 0x02, 0x7f, 0x7f, 0x00, 0x60, 0x00, 0x00, // type
+            0x02, 0x1f, 0x01, 0x14, b'f', b'l', b'u', b'e', b'n', b't', b'b', b'a', b's', b'e',
+            b'_', b'v', b'1', b'p', b'r', b'e', b'v', b'i', b'e', b'w', 0x06, b'_', b'w', b'r',
+            b'i', b't', b'e', 0x00, 0x00, // import
+            0x03, 0x02, 0x01, 0x01, // function
+            0x05, 0x04, 0x01, 0x01, 0x01, 0x10, // memory
+            0x07, 0x11, 0x02, 0x04, b'm', b'a', b'i', b'n', 0x00, 0x01, 0x06, b'm', b'e', b'm',
+            b'o', b'r', b'y', 0x02, 0x00, // export
+            0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // code
+        ];
+
+        let info = WasmModuleInfo::parse(&code).unwrap();
+        assert_eq!(
+            info.imports,
+            [WasmImport {
+                module: "fluentbase_v1preview".to_string(),
+                name: "_write".to_string(),
+                kind: WasmItemKind::Function,
+                signature: Some("(i32, i32) -> ()".to_string()),
+            }]
+        );
+        assert_eq!(
+            info.exported_functions().collect::<Vec<_>>(),
+            [&WasmExport {
+                name: "main".to_string(),
+                kind: WasmItemKind::Function,
+                signature: Some("() -> ()".to_string()),
+            }]
+        );
+        assert_eq!(info.memories, [WasmLimits { imported: false, initial: 1, maximum: Some(16) }]);
+        assert!(info.tables.is_empty());
+        assert_eq!(
+            info.sections.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            ["type", "import", "function", "memory", "export", "code"]
+        );
+        assert_eq!(info.sections[0].size, 9);
+
+        assert!(WasmModuleInfo::parse(&[0xef, 0x52]).is_err());
+        assert!(is_wasm(&code));
+        assert!(!is_wasm(&[0xef, 0x52]));
+        assert!(is_rwasm(&[0xef, 0x52]));
+        assert!(!is_rwasm(&code));
+    }
+
+    #[test]
+    fn adds_constructor_args() {
+        // (module (func))
+        let code = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type
+            0x03, 0x02, 0x01, 0x00, // function
+            0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // code
+        ];
+
+        let initcode = with_constructor_args(&code, &[0x2a; 32]);
+        assert!(initcode.starts_with(&code));
+        let info = WasmModuleInfo::parse(&initcode).unwrap();
+        assert_eq!(
+            info.sections.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            ["type", "function", "code", "custom:input"]
+        );
+        assert_eq!(info.sections[3].size, 1 + CONSTRUCTOR_INPUT_SECTION.len() + 32);
+
+        assert_eq!(constructor_args(&initcode).unwrap(), Some(Bytes::from([0x2a; 32])));
+        assert_eq!(constructor_args(&code).unwrap(), None);
+        assert_eq!(without_metadata_sections(&initcode).unwrap(), initcode);
+        assert!(constructor_args(&[0xef, 0x52]).is_err());
+    }
+
+    #[test]
+    fn strips_metadata_sections() {
+        // (module (func $main)) with a `name` section
+        let code = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type
+            0x03, 0x02, 0x01, 0x00, // function
+            0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // code
+            0x00, 0x0e, 0x04, b'n', b'a', b'm', b'e', 0x01, 0x07, 0x01, 0x00, 0x04, b'm', b'a',
+            b'i', b'n', // name
+        ];
+
+        let stripped = without_metadata_sections(&code).unwrap();
+        assert_eq!(stripped, code[..24]);
+        assert_eq!(without_metadata_sections(&stripped).unwrap(), stripped);
+
+        let initcode = with_constructor_args(&code, &[0x2a; 32]);
+        let stripped = without_metadata_sections(&initcode).unwrap();
+        assert_eq!(stripped, with_constructor_args(&code[..24], &[0x2a; 32]));
+    }
+
+    #[test]
+    fn checks_code_size() {
+        assert!(check_code_size("counter", WASM_MAX_CODE_SIZE).is_ok());
+        let err = check_code_size("counter", WASM_MAX_CODE_SIZE + 1).unwrap_err();
+        assert!(err.to_string().contains("above the wasm size limit"), "{err}");
+
+        assert_eq!(check_rwasm_size("counter", RWASM_MAX_CODE_SIZE), None);
+        let warning = check_rwasm_size("counter", RWASM_MAX_CODE_SIZE + 1).unwrap();
+        assert!(warning.contains("above the rWasm size limit"), "{warning}");
+    }
+}
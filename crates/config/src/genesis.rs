@@ -0,0 +1,69 @@
+//! Fluent genesis configuration types.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{convert::Infallible, fmt, path::PathBuf, str::FromStr};
+
+/// The Fluent genesis providing the system contracts and runtime precompiles of the local
+/// backend.
+///
+/// ```toml
+/// fluent_genesis = "strict"
+/// # or
+/// fluent_genesis = "genesis/genesis-devnet.json.gz"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FluentGenesis {
+    /// The embedded mainnet genesis with the EIP-170 contract size check of the EVM runtime
+    /// removed, so oversized contracts can be deployed in tests and scripts.
+    #[default]
+    Permissive,
+    /// The embedded mainnet genesis, enforcing the same limits as the chain.
+    Strict,
+    /// A genesis JSON file, optionally gzip compressed (`.gz`).
+    Path(PathBuf),
+}
+
+impl FluentGenesis {
+    /// Returns the path of the genesis file, if not an embedded genesis.
+    pub fn as_path(&self) -> Option<&PathBuf> {
+        match self {
+            Self::Path(path) => Some(path),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FluentGenesis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Permissive => f.write_str("permissive"),
+            Self::Strict => f.write_str("strict"),
+            Self::Path(path) => path.display().fmt(f),
+        }
+    }
+}
+
+impl FromStr for FluentGenesis {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "permissive" => Self::Permissive,
+            "strict" => Self::Strict,
+            path => Self::Path(path.into()),
+        })
+    }
+}
+
+impl Serialize for FluentGenesis {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FluentGenesis {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Ok(genesis) = String::deserialize(deserializer)?.parse();
+        Ok(genesis)
+    }
+}
@@ -119,6 +119,12 @@
 mod vyper;
 pub use vyper::VyperConfig;
 
+mod rust;
+pub use rust::{RustArtifactKind, RustBuildConfig, RustConfig, RustContractsConfig};
+
+mod genesis;
+pub use genesis::FluentGenesis;
+
 mod bind_json;
 use bind_json::BindJsonConfig;
 
@@ -575,8 +581,16 @@ pub struct Config {
     /// Whether the contract is wasm
     pub wasm: bool,
 
-    /// Do not use docker for rust (wasm) contracts
+    /// Do not use docker for rust (wasm) contracts, build them natively with the toolchain pinned
+    /// through rustup instead
     pub no_docker: bool,
+
+    /// Configuration for rust (wasm) contracts
+    pub rust: RustConfig,
+
+    /// The Fluent genesis of the local backend: `permissive`, `strict` or the path to a genesis
+    /// JSON file
+    pub fluent_genesis: FluentGenesis,
 }
 
 /// Diagnostic level (minimum) at which the process should finish with a non-zero exit.
@@ -710,6 +724,7 @@ impl Config {
         "soldeer",
         "vyper",
         "bind_json",
+        "rust",
     ];
 
     pub(crate) fn is_standalone_section<T: ?Sized + PartialEq<str>>(section: &T) -> bool {
@@ -991,6 +1006,10 @@ fn p(root: &Path, rem: &Path) -> PathBuf {
 
         self.fs_permissions.join_all(&root);
 
+        if let FluentGenesis::Path(path) = &mut self.fluent_genesis {
+            *path = p(&root, path);
+        }
+
         if let Some(model_checker) = &mut self.model_checker {
             model_checker.contracts = std::mem::take(&mut model_checker.contracts)
                 .into_iter()
@@ -2655,6 +2674,8 @@ fn default() -> Self {
             _non_exhaustive: (),
             wasm: false,
             no_docker: false,
+            rust: Default::default(),
+            fluent_genesis: Default::default(),
         }
     }
 }
@@ -5252,6 +5273,88 @@ fn test_parse_vyper() {
         });
     }
 
+    #[test]
+    fn test_parse_rust() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [rust]
+                jobs = 2
+                check_reproducible = true
+                features = ["std"]
+                wasm_opt = true
+
+                [rust.contracts]
+                include = ["src/contracts/*"]
+                exclude = ["src/**/legacy"]
+
+                [rust.packages.counter]
+                features = []
+                no_default_features = true
+                artifacts = ["abi", "foundry"]
+            "#,
+            )?;
+
+            let config = Config::load().unwrap();
+            assert_eq!(config.rust.jobs, 2);
+            assert!(config.rust.check_reproducible);
+            assert_eq!(
+                config.rust.contracts,
+                RustContractsConfig {
+                    include: vec!["src/contracts/*".to_string()],
+                    exclude: vec!["src/**/legacy".to_string()],
+                }
+            );
+            assert_eq!(
+                config.rust.package("counter"),
+                RustBuildConfig {
+                    features: Some(vec![]),
+                    no_default_features: Some(true),
+                    wasm_opt: Some(true),
+                    artifacts: Some(vec![RustArtifactKind::Abi, RustArtifactKind::Foundry]),
+                    ..Default::default()
+                }
+            );
+            assert_eq!(
+                config.rust.package("erc20"),
+                RustBuildConfig {
+                    features: Some(vec!["std".to_string()]),
+                    wasm_opt: Some(true),
+                    ..Default::default()
+                }
+            );
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_parse_fluent_genesis() {
+        figment::Jail::expect_with(|jail| {
+            let config = Config::load().unwrap();
+            assert_eq!(config.fluent_genesis, FluentGenesis::Permissive);
+
+            jail.create_file("foundry.toml", r#"fluent_genesis = "strict""#)?;
+            let config = Config::load().unwrap();
+            assert_eq!(config.fluent_genesis, FluentGenesis::Strict);
+
+            jail.create_file("foundry.toml", r#"fluent_genesis = "genesis/devnet.json.gz""#)?;
+            let config = Config::load().unwrap();
+            assert_eq!(config.fluent_genesis, FluentGenesis::Path("genesis/devnet.json.gz".into()));
+            assert_eq!(
+                config.canonic_at(jail.directory()).fluent_genesis,
+                FluentGenesis::Path(canonic(jail.directory()).join("genesis/devnet.json.gz"))
+            );
+
+            jail.set_env("FOUNDRY_FLUENT_GENESIS", "permissive");
+            let config = Config::load().unwrap();
+            assert_eq!(config.fluent_genesis, FluentGenesis::Permissive);
+
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_parse_soldeer() {
         figment::Jail::expect_with(|jail| {
@@ -0,0 +1,143 @@
+//! Rust (WASM) contracts specific configuration types.
+
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// Configuration of the Rust contracts build, the `[rust]` section.
+///
+/// ```toml
+/// [rust]
+/// features = ["std"]
+/// wasm_opt = true
+///
+/// [rust.contracts]
+/// include = ["src/contracts/*"]
+/// exclude = ["src/**/legacy"]
+///
+/// [rust.packages.power-calculator]
+/// no_default_features = true
+/// artifacts = ["abi", "foundry", "rwasm", "metadata"]
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RustConfig {
+    /// Number of contracts to build in parallel, `0` uses the number of logical cores.
+    #[serde(default)]
+    pub jobs: usize,
+    /// Whether to build every contract both natively and with Docker and fail if the rWasm output
+    /// differs.
+    #[serde(default)]
+    pub check_reproducible: bool,
+    /// Build options applied to all contracts.
+    #[serde(flatten)]
+    pub build: RustBuildConfig,
+    /// Per-package overrides of the build options, keyed by package name.
+    #[serde(default)]
+    pub packages: BTreeMap<String, RustBuildConfig>,
+    /// Which crates of the source directory are treated as contracts.
+    #[serde(default)]
+    pub contracts: RustContractsConfig,
+}
+
+impl RustConfig {
+    /// Returns the build options of the given package, with its overrides applied.
+    pub fn package(&self, name: &str) -> RustBuildConfig {
+        match self.packages.get(name) {
+            Some(overrides) => self.build.clone().merge(overrides.clone()),
+            None => self.build.clone(),
+        }
+    }
+}
+
+/// Include and exclude globs selecting the crates treated as contracts, the `[rust.contracts]`
+/// section.
+///
+/// Globs are matched against crate directories relative to the project root. By default all
+/// `cdylib` crates depending on `fluentbase-sdk` are contracts.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RustContractsConfig {
+    /// Only crates matching any of these globs are contracts, all crates if empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Crates matching any of these globs are never contracts.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+/// Build options of Rust contracts. Unset options use the builder defaults.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RustBuildConfig {
+    /// Cargo features to activate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    /// Whether to disable the default features of the crate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_default_features: Option<bool>,
+    /// Whether to optimize the wasm module with `wasm-opt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm_opt: Option<bool>,
+    /// Whether to require `Cargo.lock` to be up to date, see `cargo build --locked`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+    /// The Rust toolchain to build with, e.g. `1.92.0-x86_64-unknown-linux-gnu`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toolchain: Option<String>,
+    /// The tag of the fluentbase build image, defaults to the tag matching the crate's SDK
+    /// version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docker_tag: Option<String>,
+    /// The artifacts to generate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifacts: Option<Vec<RustArtifactKind>>,
+}
+
+impl RustBuildConfig {
+    /// Applies the options set in `overrides` on top of `self`.
+    pub fn merge(self, overrides: Self) -> Self {
+        Self {
+            features: overrides.features.or(self.features),
+            no_default_features: overrides.no_default_features.or(self.no_default_features),
+            wasm_opt: overrides.wasm_opt.or(self.wasm_opt),
+            locked: overrides.locked.or(self.locked),
+            toolchain: overrides.toolchain.or(self.toolchain),
+            docker_tag: overrides.docker_tag.or(self.docker_tag),
+            artifacts: overrides.artifacts.or(self.artifacts),
+        }
+    }
+}
+
+/// An artifact generated by the fluentbase builder.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, clap::ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum RustArtifactKind {
+    /// The Solidity interface, `interface.sol`.
+    Solidity,
+    /// The contract ABI, `abi.json`.
+    Abi,
+    /// The Foundry artifact, `foundry.json`.
+    Foundry,
+    /// The rWasm module, `lib.rwasm`.
+    Rwasm,
+    /// The build metadata, `metadata.json`.
+    Metadata,
+}
+
+impl RustArtifactKind {
+    /// Returns the name of the artifact kind.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Solidity => "solidity",
+            Self::Abi => "abi",
+            Self::Foundry => "foundry",
+            Self::Rwasm => "rwasm",
+            Self::Metadata => "metadata",
+        }
+    }
+}
+
+impl fmt::Display for RustArtifactKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
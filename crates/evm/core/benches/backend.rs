@@ -0,0 +1,46 @@
+//! Benchmarks of setting up backends, which every test contract and fuzz run does.
+//!
+//! Backends share the state of the Fluent genesis, see [`load_fluent_genesis`]. The `*/per_backend`
+//! benchmarks rebuild the genesis state for every backend instead, as before it was shared.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use foundry_evm_core::backend::{Backend, GenesisState, load_fluent_genesis, read_fluent_genesis};
+use std::{hint::black_box, sync::Arc};
+
+fn spawn(c: &mut Criterion) {
+    let mut g = c.benchmark_group("spawn");
+
+    g.bench_function("per_backend", |b| {
+        b.iter(|| {
+            let genesis = read_fluent_genesis(&Default::default()).unwrap();
+            Backend::spawn_with_genesis(None, Arc::new(GenesisState::new(genesis))).unwrap()
+        })
+    });
+
+    // warm up the shared genesis state, it is loaded once per process
+    load_fluent_genesis(&Default::default()).unwrap();
+    g.bench_function("shared", |b| b.iter(|| Backend::spawn(None).unwrap()));
+
+    g.finish();
+}
+
+fn clone_empty(c: &mut Criterion) {
+    let mut g = c.benchmark_group("clone_empty");
+    let backend = Backend::spawn(None).unwrap();
+    let genesis = load_fluent_genesis(&Default::default()).unwrap();
+
+    // `clone_empty` used to clone the genesis and rebuild its accounts
+    g.bench_function("per_backend", |b| {
+        b.iter(|| {
+            black_box(GenesisState::new(genesis.genesis.clone()));
+            backend.clone_empty()
+        })
+    });
+
+    g.bench_function("shared", |b| b.iter(|| backend.clone_empty()));
+
+    g.finish();
+}
+
+criterion_group!(benches, spawn, clone_empty);
+criterion_main!(benches);
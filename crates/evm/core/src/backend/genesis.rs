@@ -0,0 +1,186 @@
+//! Fluent genesis of the local backend.
+
+use super::FoundryEvmInMemoryDB;
+use alloy_genesis::Genesis;
+use alloy_primitives::{U256, map::HashMap};
+use eyre::WrapErr;
+use flate2::read::GzDecoder;
+use foundry_config::FluentGenesis;
+use parking_lot::Mutex;
+use revm::{
+    DatabaseCommit,
+    bytecode::Bytecode,
+    database::CacheDB,
+    primitives::KECCAK_EMPTY,
+    state::{EvmState, EvmStorageSlot},
+};
+use std::{
+    io::Read,
+    path::Path,
+    sync::{Arc, LazyLock},
+};
+
+/// Permissive variant of the strict `genesis-mainnet-v1.2.0.json.gz`: the Fluent EVM runtime at
+/// `PRECOMPILE_EVM_RUNTIME` has its EIP-170 (24 KB deployed-code) check removed so `forge script` /
+/// `forge test` can deploy contracts whose size is larger than what the chain accepts. Foundry's
+/// `check_contract_sizes` (crates/script/src/lib.rs) emits a pre-broadcast warning for any
+/// oversized CREATE in the collected tx set, so actual on-chain rejection is still surfaced to the
+/// user before they broadcast.
+///
+/// To regenerate the permissive build, patch `contracts/evm/lib.rs` in a fluentbase clone to drop
+/// the `output.len() > EVM_MAX_CODE_SIZE` branch in `deploy_entry` and run
+/// `cargo build --release -p fluentbase-genesis`.
+const PERMISSIVE_GENESIS: &[u8] =
+    include_bytes!("../../../genesis/genesis-mainnet-v1.2.0-permissive.json.gz");
+
+/// The mainnet genesis, as deployed on chain.
+const STRICT_GENESIS: &[u8] = include_bytes!("../../../genesis/genesis-mainnet-v1.2.0.json.gz");
+
+/// Genesis states that were already loaded, shared by all backends of the process.
+static LOADED: LazyLock<Mutex<HashMap<FluentGenesis, Arc<GenesisState>>>> =
+    LazyLock::new(Default::default);
+
+/// A parsed genesis together with the state it produces.
+///
+/// Decoding a genesis and hashing the bytecode of its system contracts is expensive, so it is done
+/// once per genesis, and backends share the result.
+#[derive(Debug)]
+pub struct GenesisState {
+    /// The parsed genesis.
+    pub genesis: Genesis,
+    /// The genesis accounts, as inserted into a new journaled state.
+    pub accounts: EvmState,
+    /// The in-memory database with the genesis accounts committed.
+    pub db: FoundryEvmInMemoryDB,
+}
+
+impl GenesisState {
+    /// Builds the state of the given genesis.
+    pub fn new(genesis: Genesis) -> Self {
+        let mut accounts = EvmState::default();
+        for (address, account) in &genesis.alloc {
+            let state_acc = accounts.entry(*address).or_default();
+            state_acc.info.balance = account.balance;
+            state_acc.info.nonce = account.nonce.unwrap_or_default();
+
+            if let Some(code) = &account.code {
+                let bytecode = Bytecode::new_raw(code.clone());
+                state_acc.info.code_hash = bytecode.hash_slow();
+                state_acc.info.code = Some(bytecode);
+            } else {
+                state_acc.info.code_hash = KECCAK_EMPTY;
+                state_acc.info.code = None;
+            }
+
+            if let Some(storage) = &account.storage {
+                state_acc.storage = storage
+                    .iter()
+                    .map(|(key, value)| {
+                        let slot = U256::from_be_bytes(key.0);
+                        let value = U256::from_be_bytes(value.0);
+
+                        (slot, EvmStorageSlot::new_changed(U256::ZERO, value, 0))
+                    })
+                    .collect();
+            }
+        }
+
+        let mut db = CacheDB::new(Default::default());
+        // only touched accounts are committed
+        let mut touched = accounts.clone();
+        touched.values_mut().for_each(|account| account.mark_touch());
+        db.commit(touched);
+
+        Self { genesis, accounts, db }
+    }
+}
+
+/// Loads the given Fluent genesis and builds its state.
+///
+/// Each genesis is only loaded once, later calls return the shared state.
+pub fn load_fluent_genesis(genesis: &FluentGenesis) -> eyre::Result<Arc<GenesisState>> {
+    let mut loaded = LOADED.lock();
+    if let Some(state) = loaded.get(genesis) {
+        return Ok(state.clone());
+    }
+    let state = Arc::new(GenesisState::new(read_fluent_genesis(genesis)?));
+    loaded.insert(genesis.clone(), state.clone());
+    Ok(state)
+}
+
+/// Reads the given Fluent genesis, without sharing it like [`load_fluent_genesis`] does.
+///
+/// Genesis files ending in `.gz` are gzip decompressed.
+pub fn read_fluent_genesis(genesis: &FluentGenesis) -> eyre::Result<Genesis> {
+    match genesis {
+        FluentGenesis::Permissive => decode_gz(PERMISSIVE_GENESIS),
+        FluentGenesis::Strict => decode_gz(STRICT_GENESIS),
+        FluentGenesis::Path(path) => load_genesis_file(path)
+            .wrap_err_with(|| format!("failed to load genesis file {}", path.display())),
+    }
+}
+
+fn load_genesis_file(path: &Path) -> eyre::Result<Genesis> {
+    let content = std::fs::read(path)?;
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        decode_gz(&content)
+    } else {
+        Ok(serde_json::from_slice(&content)?)
+    }
+}
+
+fn decode_gz(compressed: &[u8]) -> eyre::Result<Genesis> {
+    let mut json = Vec::new();
+    GzDecoder::new(compressed).read_to_end(&mut json).wrap_err("failed to decompress genesis")?;
+    serde_json::from_slice(&json).wrap_err("failed to parse genesis JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, address};
+    use revm::DatabaseRef;
+
+    #[test]
+    fn builds_and_shares_genesis_state() {
+        let genesis = r#"{
+            "config": { "chainId": 20994 },
+            "nonce": "0x0",
+            "timestamp": "0x0",
+            "extraData": "0x",
+            "gasLimit": "0x1c9c380",
+            "difficulty": "0x0",
+            "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "coinbase": "0x0000000000000000000000000000000000000000",
+            "alloc": {
+                "0x0000000000000000000000000000000000520001": {
+                    "balance": "0x0",
+                    "code": "0x6001",
+                    "storage": {
+                        "0x0000000000000000000000000000000000000000000000000000000000000001": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                    }
+                },
+                "0x1000000000000000000000000000000000000001": { "balance": "0x10" }
+            }
+        }"#;
+        let path = std::env::temp_dir().join(format!("fluent-genesis-{}.json", std::process::id()));
+        std::fs::write(&path, genesis).unwrap();
+        let fluent_genesis = FluentGenesis::Path(path.clone());
+
+        let state = load_fluent_genesis(&fluent_genesis).unwrap();
+        assert!(Arc::ptr_eq(&state, &load_fluent_genesis(&fluent_genesis).unwrap()));
+        std::fs::remove_file(path).unwrap();
+
+        let runtime = address!("0x0000000000000000000000000000000000520001");
+        let account = &state.accounts[&runtime];
+        assert_eq!(
+            account.info.code_hash,
+            Bytecode::new_raw(Bytes::from_static(&[0x60, 0x01])).hash_slow()
+        );
+        assert_eq!(account.storage[&U256::from(1)].present_value, U256::from(2));
+
+        let wallet = address!("0x1000000000000000000000000000000000000001");
+        assert_eq!(state.db.basic_ref(wallet).unwrap().unwrap().balance, U256::from(0x10));
+        assert_eq!(state.db.storage_ref(runtime, U256::from(1)).unwrap(), U256::from(2));
+    }
+}
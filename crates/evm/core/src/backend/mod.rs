@@ -10,7 +10,7 @@
 };
 use alloy_consensus::Typed2718;
 use alloy_evm::Evm;
-use alloy_genesis::{Genesis, GenesisAccount};
+use alloy_genesis::GenesisAccount;
 use alloy_network::{AnyRpcBlock, AnyTxEnvelope, TransactionResponse};
 use alloy_primitives::{Address, B256, TxKind, U256, keccak256, uint};
 use alloy_rpc_types::{BlockNumberOrTag, Transaction, TransactionRequest};
@@ -35,6 +35,7 @@
 use std::{
     collections::{BTreeMap, HashMap, HashSet},
     fmt::Debug,
+    sync::Arc,
     time::Instant,
 };
 
@@ -47,6 +48,9 @@
 mod cow;
 pub use cow::CowBackend;
 
+mod genesis;
+pub use genesis::{GenesisState, load_fluent_genesis, read_fluent_genesis};
+
 mod in_memory_db;
 pub use in_memory_db::{EmptyDBWrapper, FoundryEvmInMemoryDB, MemDb};
 
@@ -476,6 +480,15 @@ pub fn spawn(fork: Option<CreateFork>) -> eyre::Result<Self> {
         Self::new(MultiFork::spawn(), fork)
     }
 
+    /// Same as [`spawn`](Self::spawn), but with the given genesis instead of the default Fluent
+    /// genesis.
+    pub fn spawn_with_genesis(
+        fork: Option<CreateFork>,
+        genesis: Arc<GenesisState>,
+    ) -> eyre::Result<Self> {
+        Self::new_with_genesis(MultiFork::spawn(), fork, genesis)
+    }
+
     /// Creates a new instance of `Backend`
     ///
     /// If `fork` is `Some` this will use a `fork` database, otherwise with an in-memory
@@ -483,24 +496,34 @@ pub fn spawn(fork: Option<CreateFork>) -> eyre::Result<Self> {
     ///
     /// Prefer using [`spawn`](Self::spawn) instead.
     pub fn new(forks: MultiFork, fork: Option<CreateFork>) -> eyre::Result<Self> {
+        let genesis = load_fluent_genesis(&Default::default())?;
+        Self::new_with_genesis(forks, fork, genesis)
+    }
+
+    /// Creates a new instance of `Backend` whose initial state is the given `genesis`.
+    ///
+    /// See [`new`](Self::new).
+    pub fn new_with_genesis(
+        forks: MultiFork,
+        fork: Option<CreateFork>,
+        genesis: Arc<GenesisState>,
+    ) -> eyre::Result<Self> {
         trace!(target: "backend", forking_mode=?fork.is_some(), "creating executor backend");
 
         // Note: this will take of registering the `fork`
         let inner = BackendInner {
             persistent_accounts: HashSet::from(DEFAULT_PERSISTENT_ACCOUNTS),
-            ..Default::default()
+            ..BackendInner::new(genesis)
         };
 
-        let initial_journaled_state = inner.new_journaled_state();
-
         let mut backend = Self {
             forks,
-            mem_db: CacheDB::new(Default::default()),
+            // the genesis accounts are already committed to the shared database
+            mem_db: inner.genesis.db.clone(),
             fork_init_journaled_state: inner.new_journaled_state(),
             active_fork_ids: None,
             inner,
         };
-        backend.commit(initial_journaled_state.state);
 
         if let Some(fork) = fork {
             let (fork_id, fork, _) = backend.forks.create_fork(fork)?;
@@ -558,7 +581,7 @@ pub fn clone_empty(&self) -> Self {
             mem_db: CacheDB::new(Default::default()),
             fork_init_journaled_state: self.inner.new_journaled_state(),
             active_fork_ids: None,
-            inner: Default::default(),
+            inner: BackendInner::new(self.inner.genesis.clone()),
         }
     }
 
@@ -1684,15 +1707,34 @@ pub struct BackendInner {
     /// All accounts that are allowed to execute cheatcodes
     pub cheatcode_access_accounts: HashSet<Address>,
 
-    /// Genesis
-    pub genesis: Genesis,
+    /// The genesis providing the initial state, shared across backends.
+    pub genesis: Arc<GenesisState>,
 }
 
 impl BackendInner {
-    // pub fn with_genesis(&mut self, genesis: Genesis) -> Self {
-    //     self.genesis = genesis;
-    //     self
-    // }
+    /// Creates a new instance whose initial state is the given `genesis`.
+    pub fn new(genesis: Arc<GenesisState>) -> Self {
+        Self {
+            launched_with_fork: None,
+            issued_local_fork_ids: Default::default(),
+            created_forks: Default::default(),
+            forks: vec![],
+            state_snapshots: Default::default(),
+            has_state_snapshot_failure: false,
+            caller: None,
+            next_fork_id: Default::default(),
+            persistent_accounts: Default::default(),
+            spec_id: SpecId::default(),
+            // grant the cheatcode,default test and caller address access to execute cheatcodes
+            // itself
+            cheatcode_access_accounts: HashSet::from([
+                CHEATCODE_ADDRESS,
+                TEST_CONTRACT_ADDRESS,
+                CALLER,
+            ]),
+            genesis,
+        }
+    }
 
     pub fn ensure_fork_id(&self, id: LocalForkId) -> eyre::Result<&ForkId> {
         self.issued_local_fork_ids
@@ -1864,31 +1906,8 @@ pub fn new_journaled_state(&self) -> JournaledState {
             .warm_addresses
             .set_precompile_addresses(self.precompiles().addresses().copied().collect());
 
-        for (address, account) in self.genesis.alloc.clone() {
-            let state_acc = journal.state.entry(address).or_default();
-            state_acc.info.balance = account.balance;
-            state_acc.info.nonce = account.nonce.unwrap_or_default();
-
-            if let Some(code) = &account.code {
-                let bytecode = Bytecode::new_raw(code.0.clone().into());
-                state_acc.info.code_hash = bytecode.hash_slow();
-                state_acc.info.code = Some(bytecode);
-            } else {
-                state_acc.info.code_hash = KECCAK_EMPTY;
-                state_acc.info.code = None;
-            }
-
-            if let Some(storage) = &account.storage {
-                state_acc.storage = storage
-                    .iter()
-                    .map(|(key, value)| {
-                        let slot = U256::from_be_bytes(key.0);
-                        let value = U256::from_be_bytes(value.0);
-
-                        (slot, EvmStorageSlot::new_changed(U256::ZERO, value, 0))
-                    })
-                    .collect();
-            }
+        journal.state = self.genesis.accounts.clone();
+        for &address in self.genesis.genesis.alloc.keys() {
             journal.touch(address);
         }
 
@@ -1898,48 +1917,9 @@ pub fn new_journaled_state(&self) -> JournaledState {
 
 impl Default for BackendInner {
     fn default() -> Self {
-        // Permissive variant of the strict `genesis-mainnet-v1.2.0.json.gz`: the Fluent EVM
-        // runtime at `PRECOMPILE_EVM_RUNTIME` has its EIP-170 (24 KB deployed-code) check
-        // removed so `forge script` / `forge test` can deploy contracts whose size is larger
-        // than what the chain accepts. Foundry's `check_contract_sizes` (crates/script/src/lib.rs)
-        // emits a pre-broadcast warning for any oversized CREATE in the collected tx set, so
-        // actual on-chain rejection is still surfaced to the user before they broadcast.
-        //
-        // The strict variant remains checked in alongside it for reference / future reverts;
-        // to regenerate the permissive build, patch `contracts/evm/lib.rs` in a fluentbase
-        // clone to drop the `output.len() > EVM_MAX_CODE_SIZE` branch in `deploy_entry` and
-        // run `cargo build --release -p fluentbase-genesis`.
-        let json_file_compressed =
-            include_bytes!("../../../genesis/genesis-mainnet-v1.2.0-permissive.json.gz");
-
-        use flate2::read::GzDecoder;
-        use std::io::Read;
-        let mut decoder = GzDecoder::new(&json_file_compressed[..]);
-        let mut json_string = String::new();
-        decoder.read_to_string(&mut json_string).expect("failed to decompress a genesis gz file");
-        let genesis = serde_json::from_str::<Genesis>(&json_string)
-            .expect("failed to parse a genesis JSON file");
-
-        Self {
-            launched_with_fork: None,
-            issued_local_fork_ids: Default::default(),
-            created_forks: Default::default(),
-            forks: vec![],
-            state_snapshots: Default::default(),
-            has_state_snapshot_failure: false,
-            caller: None,
-            next_fork_id: Default::default(),
-            persistent_accounts: Default::default(),
-            spec_id: SpecId::default(),
-            // grant the cheatcode,default test and caller address access to execute cheatcodes
-            // itself
-            cheatcode_access_accounts: HashSet::from([
-                CHEATCODE_ADDRESS,
-                TEST_CONTRACT_ADDRESS,
-                CALLER,
-            ]),
-            genesis,
-        }
+        Self::new(
+            load_fluent_genesis(&Default::default()).expect("failed to load the embedded genesis"),
+        )
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::rwasm_evm::RwasmEthEvm;
 use alloy_evm::{Database, EthEvm, Evm, EvmEnv, eth::EthEvmContext};
 use alloy_op_evm::OpEvm;
 use alloy_primitives::{Address, Bytes};
@@ -21,10 +22,11 @@
 type EitherExecResult<DBError, HaltReason, TxError> =
     Result<ExecutionResult<HaltReason>, EVMError<DBError, TxError>>;
 
-/// [`EitherEvm`] delegates its calls to one of the two evm implementations; either [`EthEvm`] or
-/// [`OpEvm`].
+/// [`EitherEvm`] delegates its calls to one of the evm implementations; either [`EthEvm`],
+/// [`OpEvm`] or [`RwasmEthEvm`].
 ///
-/// Calls are delegated to [`OpEvm`] only if optimism is enabled.
+/// Calls are delegated to [`OpEvm`] only if optimism is enabled, and to [`RwasmEthEvm`] only if
+/// fluent is enabled.
 ///
 /// The call delegation is handled via its own implementation of the [`Evm`] trait.
 ///
@@ -42,6 +44,8 @@ pub enum EitherEvm<DB, I, P>
     Eth(EthEvm<DB, I, P>),
     /// [`OpEvm`] implementation.
     Op(OpEvm<DB, I, P>),
+    /// [`RwasmEthEvm`] implementation.
+    Rwasm(RwasmEthEvm<DB, I, P>),
 }
 
 impl<DB, I, P> EitherEvm<DB, I, P>
@@ -112,6 +116,7 @@ fn block(&self) -> &BlockEnv {
         match self {
             Self::Eth(evm) => evm.block(),
             Self::Op(evm) => evm.block(),
+            Self::Rwasm(evm) => evm.block(),
         }
     }
 
@@ -119,6 +124,7 @@ fn chain_id(&self) -> u64 {
         match self {
             Self::Eth(evm) => evm.chain_id(),
             Self::Op(evm) => evm.chain_id(),
+            Self::Rwasm(evm) => evm.chain_id(),
         }
     }
 
@@ -126,6 +132,7 @@ fn components(&self) -> (&Self::DB, &Self::Inspector, &Self::Precompiles) {
         match self {
             Self::Eth(evm) => evm.components(),
             Self::Op(evm) => evm.components(),
+            Self::Rwasm(evm) => evm.components(),
         }
     }
 
@@ -133,6 +140,7 @@ fn components_mut(&mut self) -> (&mut Self::DB, &mut Self::Inspector, &mut Self:
         match self {
             Self::Eth(evm) => evm.components_mut(),
             Self::Op(evm) => evm.components_mut(),
+            Self::Rwasm(evm) => evm.components_mut(),
         }
     }
 
@@ -140,6 +148,7 @@ fn db_mut(&mut self) -> &mut Self::DB {
         match self {
             Self::Eth(evm) => evm.db_mut(),
             Self::Op(evm) => evm.db_mut(),
+            Self::Rwasm(evm) => evm.db_mut(),
         }
     }
 
@@ -150,6 +159,7 @@ fn into_db(self) -> Self::DB
         match self {
             Self::Eth(evm) => evm.into_db(),
             Self::Op(evm) => evm.into_db(),
+            Self::Rwasm(evm) => evm.into_db(),
         }
     }
 
@@ -163,6 +173,7 @@ fn finish(self) -> (Self::DB, EvmEnv<Self::Spec>)
                 let (db, env) = evm.finish();
                 (db, map_env(env))
             }
+            Self::Rwasm(evm) => evm.finish(),
         }
     }
 
@@ -170,6 +181,7 @@ fn precompiles(&self) -> &Self::Precompiles {
         match self {
             Self::Eth(evm) => evm.precompiles(),
             Self::Op(evm) => evm.precompiles(),
+            Self::Rwasm(evm) => evm.precompiles(),
         }
     }
 
@@ -177,6 +189,7 @@ fn precompiles_mut(&mut self) -> &mut Self::Precompiles {
         match self {
             Self::Eth(evm) => evm.precompiles_mut(),
             Self::Op(evm) => evm.precompiles_mut(),
+            Self::Rwasm(evm) => evm.precompiles_mut(),
         }
     }
 
@@ -184,6 +197,7 @@ fn inspector(&self) -> &Self::Inspector {
         match self {
             Self::Eth(evm) => evm.inspector(),
             Self::Op(evm) => evm.inspector(),
+            Self::Rwasm(evm) => evm.inspector(),
         }
     }
 
@@ -191,6 +205,7 @@ fn inspector_mut(&mut self) -> &mut Self::Inspector {
         match self {
             Self::Eth(evm) => evm.inspector_mut(),
             Self::Op(evm) => evm.inspector_mut(),
+            Self::Rwasm(evm) => evm.inspector_mut(),
         }
     }
 
@@ -198,6 +213,7 @@ fn enable_inspector(&mut self) {
         match self {
             Self::Eth(evm) => evm.enable_inspector(),
             Self::Op(evm) => evm.enable_inspector(),
+            Self::Rwasm(evm) => evm.enable_inspector(),
         }
     }
 
@@ -205,6 +221,7 @@ fn disable_inspector(&mut self) {
         match self {
             Self::Eth(evm) => evm.disable_inspector(),
             Self::Op(evm) => evm.disable_inspector(),
+            Self::Rwasm(evm) => evm.disable_inspector(),
         }
     }
 
@@ -212,6 +229,7 @@ fn set_inspector_enabled(&mut self, enabled: bool) {
         match self {
             Self::Eth(evm) => evm.set_inspector_enabled(enabled),
             Self::Op(evm) => evm.set_inspector_enabled(enabled),
+            Self::Rwasm(evm) => evm.set_inspector_enabled(enabled),
         }
     }
 
@@ -222,6 +240,7 @@ fn into_env(self) -> EvmEnv<Self::Spec>
         match self {
             Self::Eth(evm) => evm.into_env(),
             Self::Op(evm) => map_env(evm.into_env()),
+            Self::Rwasm(evm) => evm.into_env(),
         }
     }
 
@@ -235,6 +254,10 @@ fn transact(
                 self.map_eth_result(eth)
             }
             Self::Op(evm) => evm.transact(tx),
+            Self::Rwasm(evm) => {
+                let rwasm = evm.transact(tx.into_tx_env().base);
+                self.map_eth_result(rwasm)
+            }
         }
     }
 
@@ -251,6 +274,10 @@ fn transact_commit(
                 self.map_exec_result(eth)
             }
             Self::Op(evm) => evm.transact_commit(tx),
+            Self::Rwasm(evm) => {
+                let rwasm = evm.transact_commit(tx.into_tx_env().base);
+                self.map_exec_result(rwasm)
+            }
         }
     }
 
@@ -264,6 +291,10 @@ fn transact_raw(
                 self.map_eth_result(res)
             }
             Self::Op(evm) => evm.transact_raw(tx),
+            Self::Rwasm(evm) => {
+                let res = evm.transact_raw(tx.base);
+                self.map_eth_result(res)
+            }
         }
     }
 
@@ -279,6 +310,10 @@ fn transact_system_call(
                 self.map_eth_result(eth)
             }
             Self::Op(evm) => evm.transact_system_call(caller, contract, data),
+            Self::Rwasm(evm) => {
+                let rwasm = evm.transact_system_call(caller, contract, data);
+                self.map_eth_result(rwasm)
+            }
         }
     }
 }
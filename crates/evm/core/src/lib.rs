@@ -40,6 +40,7 @@ pub mod abi {
 pub mod ic;
 pub mod opts;
 pub mod precompiles;
+pub mod rwasm_evm;
 pub mod state_snapshot;
 pub mod utils;
 
@@ -1,6 +1,7 @@
 use super::fork::environment;
 use crate::{
     EvmEnv,
+    backend::{GenesisState, load_fluent_genesis},
     constants::DEFAULT_CREATE2_DEPLOYER,
     fork::{CreateFork, configure_env},
 };
@@ -13,7 +14,7 @@
     ALCHEMY_FREE_TIER_CUPS,
     provider::{ProviderBuilder, RetryProvider},
 };
-use foundry_config::{Chain, Config, GasLimit};
+use foundry_config::{Chain, Config, FluentGenesis, GasLimit};
 use foundry_evm_networks::NetworkConfigs;
 use revm::context::{BlockEnv, TxEnv};
 use serde::{Deserialize, Serialize};
@@ -88,6 +89,9 @@ pub struct EvmOpts {
     /// The CREATE2 deployer's address.
     pub create2_deployer: Address,
 
+    /// The Fluent genesis of the local backend.
+    pub fluent_genesis: FluentGenesis,
+
     /// Set genesis
     #[serde(skip)]
     pub genesis: Option<Arc<Genesis>>,
@@ -116,6 +120,7 @@ fn default() -> Self {
             enable_tx_gas_limit: false,
             networks: NetworkConfigs::default(),
             create2_deployer: DEFAULT_CREATE2_DEPLOYER,
+            fluent_genesis: FluentGenesis::default(),
             genesis: None,
         }
     }
@@ -287,6 +292,15 @@ pub fn with_genesis(mut self, genesis: Genesis) -> Self {
         self.genesis = Some(Arc::new(genesis));
         self
     }
+
+    /// Returns the genesis state of the local backend: the custom genesis if set, otherwise the
+    /// configured [`FluentGenesis`].
+    pub fn load_genesis(&self) -> eyre::Result<Arc<GenesisState>> {
+        match &self.genesis {
+            Some(genesis) => Ok(Arc::new(GenesisState::new(Genesis::clone(genesis)))),
+            None => load_fluent_genesis(&self.fluent_genesis),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -0,0 +1,204 @@
+//! The Fluent rWasm EVM over an arbitrary database.
+
+use alloy_evm::{Database, Evm, EvmEnv, eth::EthEvmContext};
+use alloy_primitives::{Address, Bytes, TxKind};
+use fluentbase_revm::{RwasmEvm, RwasmFrame};
+use revm::{
+    Context, Inspector,
+    context::{
+        BlockEnv, ContextTr, JournalTr, TxEnv,
+        result::{EVMError, ExecResultAndState, ExecutionResult, HaltReason, ResultAndState},
+    },
+    handler::{EvmTr, Handler, PrecompileProvider, instructions::EthInstructions},
+    inspector::{InspectorEvmTr, InspectorHandler},
+    interpreter::{InterpreterResult, interpreter::EthInterpreter},
+    primitives::hardfork::SpecId,
+};
+use std::marker::PhantomData;
+
+/// Gas limit of system calls, same as for the other EVM implementations.
+const SYSTEM_CALL_GAS_LIMIT: u64 = 30_000_000;
+
+type InnerEvm<DB, I, P> = RwasmEvm<
+    EthEvmContext<DB>,
+    I,
+    EthInstructions<EthInterpreter, EthEvmContext<DB>>,
+    P,
+    RwasmFrame,
+>;
+
+/// [`Evm`] implementation of the Fluent rWasm EVM, which executes both EVM bytecode and rWasm
+/// contracts the same way a Fluent node does.
+///
+/// Unlike [`FoundryEvm`](crate::evm::FoundryEvm), it works with any database and has no support
+/// for cheatcodes. It's used by anvil to run a local Fluent node.
+pub struct RwasmEthEvm<DB: Database, I, P> {
+    inner: InnerEvm<DB, I, P>,
+    inspect: bool,
+}
+
+impl<DB: Database, I, P> RwasmEthEvm<DB, I, P> {
+    /// Creates a new rWasm EVM with the given context, inspector and precompiles.
+    ///
+    /// The inspector is only called if `inspect` is true.
+    pub fn new(ctx: EthEvmContext<DB>, inspector: I, precompiles: P, inspect: bool) -> Self {
+        Self { inner: RwasmEvm::new(ctx, inspector).with_precompiles(precompiles), inspect }
+    }
+}
+
+impl<DB, I, P> Evm for RwasmEthEvm<DB, I, P>
+where
+    DB: Database,
+    I: Inspector<EthEvmContext<DB>>,
+    P: PrecompileProvider<EthEvmContext<DB>, Output = InterpreterResult>,
+{
+    type DB = DB;
+    type Error = EVMError<DB::Error>;
+    type HaltReason = HaltReason;
+    type Tx = TxEnv;
+    type Inspector = I;
+    type Precompiles = P;
+    type Spec = SpecId;
+    type BlockEnv = BlockEnv;
+
+    fn block(&self) -> &BlockEnv {
+        &self.inner.0.ctx.block
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.inner.0.ctx.cfg.chain_id
+    }
+
+    fn components(&self) -> (&Self::DB, &Self::Inspector, &Self::Precompiles) {
+        (
+            &self.inner.0.ctx.journaled_state.database,
+            &self.inner.0.inspector,
+            &self.inner.0.precompiles,
+        )
+    }
+
+    fn components_mut(&mut self) -> (&mut Self::DB, &mut Self::Inspector, &mut Self::Precompiles) {
+        (
+            &mut self.inner.0.ctx.journaled_state.database,
+            &mut self.inner.0.inspector,
+            &mut self.inner.0.precompiles,
+        )
+    }
+
+    fn db_mut(&mut self) -> &mut Self::DB {
+        &mut self.inner.0.ctx.journaled_state.database
+    }
+
+    fn precompiles(&self) -> &Self::Precompiles {
+        &self.inner.0.precompiles
+    }
+
+    fn precompiles_mut(&mut self) -> &mut Self::Precompiles {
+        &mut self.inner.0.precompiles
+    }
+
+    fn inspector(&self) -> &Self::Inspector {
+        &self.inner.0.inspector
+    }
+
+    fn inspector_mut(&mut self) -> &mut Self::Inspector {
+        &mut self.inner.0.inspector
+    }
+
+    fn set_inspector_enabled(&mut self, enabled: bool) {
+        self.inspect = enabled;
+    }
+
+    fn transact_raw(
+        &mut self,
+        tx: Self::Tx,
+    ) -> Result<ResultAndState<Self::HaltReason>, Self::Error> {
+        self.inner.0.ctx.tx = tx;
+
+        let mut handler = RwasmHandler::default();
+        let result = if self.inspect {
+            handler.inspect_run(&mut self.inner)
+        } else {
+            handler.run(&mut self.inner)
+        };
+        // clears the journal for the next transaction
+        let state = self.inner.0.ctx.journal_mut().finalize();
+
+        result.map(|result| ResultAndState::new(result, state))
+    }
+
+    fn transact_system_call(
+        &mut self,
+        caller: Address,
+        contract: Address,
+        data: Bytes,
+    ) -> Result<ExecResultAndState<ExecutionResult>, Self::Error> {
+        let tx = TxEnv {
+            caller,
+            kind: TxKind::Call(contract),
+            data,
+            gas_limit: SYSTEM_CALL_GAS_LIMIT,
+            gas_price: 0,
+            nonce: 0,
+            ..Default::default()
+        };
+
+        // system calls are neither paid for nor bound to the caller's nonce
+        let cfg = self.inner.0.ctx.cfg.clone();
+        self.inner.0.ctx.cfg.disable_base_fee = true;
+        self.inner.0.ctx.cfg.disable_nonce_check = true;
+        let result = self.transact_raw(tx);
+        self.inner.0.ctx.cfg = cfg;
+
+        let mut result = result?;
+        // the system caller and the beneficiary are not part of the state changes
+        result.state.remove(&caller);
+        if self.inner.0.ctx.block.beneficiary != contract {
+            result.state.remove(&self.inner.0.ctx.block.beneficiary);
+        }
+        Ok(result)
+    }
+
+    fn finish(self) -> (Self::DB, EvmEnv<Self::Spec>)
+    where
+        Self: Sized,
+    {
+        let Context { block: block_env, cfg: cfg_env, journaled_state, .. } = self.inner.0.ctx;
+
+        (journaled_state.database, EvmEnv { block_env, cfg_env })
+    }
+}
+
+/// Handler of the rWasm EVM, running transactions with the default execution loop.
+struct RwasmHandler<DB, I, P> {
+    _phantom: PhantomData<(DB, I, P)>,
+}
+
+impl<DB, I, P> Default for RwasmHandler<DB, I, P> {
+    fn default() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<DB, I, P> Handler for RwasmHandler<DB, I, P>
+where
+    DB: Database,
+    P: PrecompileProvider<EthEvmContext<DB>, Output = InterpreterResult>,
+{
+    type Evm = InnerEvm<DB, I, P>;
+    type Error = EVMError<DB::Error>;
+    type HaltReason = HaltReason;
+}
+
+impl<DB, I, P, EXT> InspectorHandler<EXT> for RwasmHandler<DB, I, P>
+where
+    DB: Database,
+    P: PrecompileProvider<EthEvmContext<DB>, Output = InterpreterResult>,
+    EXT: Clone + std::fmt::Debug,
+    InnerEvm<DB, I, P>: InspectorEvmTr<
+            EXT,
+            Inspector: Inspector<<InnerEvm<DB, I, P> as EvmTr>::Context, EthInterpreter>,
+        >,
+{
+    type IT = EthInterpreter;
+}
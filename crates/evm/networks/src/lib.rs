@@ -19,17 +19,26 @@
 
 pub mod celo;
 
+/// Chain IDs of the Fluent devnet, testnet and mainnet.
+pub const FLUENT_CHAIN_IDS: [u64; 3] = [20993, 20994, 25363];
+
 #[derive(Clone, Debug, Default, Parser, Copy, Serialize, Deserialize, PartialEq)]
 pub struct NetworkConfigs {
     /// Enable Optimism network features.
-    #[arg(help_heading = "Networks", long, conflicts_with = "celo")]
+    #[arg(help_heading = "Networks", long, conflicts_with_all = ["celo", "fluent"])]
     // Skipped from configs (forge) as there is no feature to be added yet.
     #[serde(skip)]
     optimism: bool,
     /// Enable Celo network features.
-    #[arg(help_heading = "Networks", long, conflicts_with = "optimism")]
+    #[arg(help_heading = "Networks", long, conflicts_with_all = ["optimism", "fluent"])]
     #[serde(default)]
     celo: bool,
+    /// Enable Fluent network features: execute with the rWasm runtime and preload the Fluent
+    /// system contracts.
+    #[arg(help_heading = "Networks", long, conflicts_with_all = ["optimism", "celo"])]
+    // Skipped from configs (forge) as tests and scripts always run on the rWasm runtime.
+    #[serde(skip)]
+    fluent: bool,
     /// Whether to bypass prevrandao.
     #[arg(skip)]
     #[serde(default)]
@@ -45,6 +54,10 @@ pub fn with_celo() -> Self {
         Self { celo: true, ..Default::default() }
     }
 
+    pub fn with_fluent() -> Self {
+        Self { fluent: true, ..Default::default() }
+    }
+
     pub fn is_optimism(&self) -> bool {
         self.optimism
     }
@@ -80,10 +93,17 @@ pub fn is_celo(&self) -> bool {
         self.celo
     }
 
+    pub fn is_fluent(&self) -> bool {
+        self.fluent
+    }
+
     pub fn with_chain_id(mut self, chain_id: u64) -> Self {
         if let Ok(NamedChain::Celo | NamedChain::CeloSepolia) = NamedChain::try_from(chain_id) {
             self.celo = true;
         }
+        if FLUENT_CHAIN_IDS.contains(&chain_id) {
+            self.fluent = true;
+        }
         self
     }
 
@@ -6,7 +6,7 @@
 use clap_complete::generate;
 use eyre::Result;
 use foundry_cli::utils;
-use foundry_common::shell;
+use foundry_common::{rust_compiler::clean_rust_caches, shell};
 use foundry_evm::inspectors::cheatcodes::{ForgeContext, set_execution_context};
 
 /// Run the `forge` command line interface.
@@ -98,6 +98,7 @@ pub fn run_command(args: Forge) -> Result<()> {
             let config = utils::load_config_with_root(root.as_deref())?;
             let project = config.project()?;
             config.cleanup(&project)?;
+            clean_rust_caches(&config.cache_path);
             Ok(())
         }
         ForgeSubcommand::Snapshot(cmd) => {
@@ -105,7 +105,7 @@ pub async fn run(self) -> Result<ProjectCompileOutput> {
             .print_names(self.names)
             .print_sizes(self.sizes)
             .ignore_eip_3860(self.ignore_eip_3860)
-            .no_docker(config.no_docker)
+            .config(&config)
             .bail(!format_json);
 
         let mut output = compiler.compile(&project)?;
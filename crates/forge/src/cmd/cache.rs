@@ -4,6 +4,7 @@
     builder::{PossibleValuesParser, TypedValueParser},
 };
 use eyre::Result;
+use foundry_common::rust_compiler::clean_rust_caches;
 use foundry_config::{Chain, Config, NamedChain, cache};
 use std::{ffi::OsStr, str::FromStr};
 use strum::VariantNames;
@@ -66,7 +67,11 @@ pub fn run(self) -> Result<()> {
                     if etherscan {
                         Config::clean_foundry_etherscan_cache()?;
                     } else {
-                        Config::clean_foundry_cache()?
+                        Config::clean_foundry_cache()?;
+                        // Rust contract builds are cached per project, invalidate them as well.
+                        if let Ok(config) = Config::load() {
+                            clean_rust_caches(&config.cache_path);
+                        }
                     }
                 }
             }
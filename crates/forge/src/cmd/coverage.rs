@@ -9,10 +9,14 @@
 use clap::{Parser, ValueEnum, ValueHint};
 use eyre::Result;
 use foundry_cli::utils::{LoadConfig, STATIC_FUZZ_SEED};
-use foundry_common::{compile::ProjectCompiler, errors::convert_solar_errors};
+use foundry_common::{
+    compile::ProjectCompiler, errors::convert_solar_errors, rust_artifact::compiled_rust_artifacts,
+};
 use foundry_compilers::{
     Artifact, ArtifactId, Project, ProjectCompileOutput, ProjectPathsConfig, VYPER_EXTENSIONS,
-    artifacts::{CompactBytecode, CompactDeployedBytecode, sourcemap::SourceMap},
+    artifacts::{
+        CompactBytecode, CompactContractBytecode, CompactDeployedBytecode, sourcemap::SourceMap,
+    },
 };
 use foundry_config::Config;
 use foundry_evm::{core::ic::IcPcMap, opts::EvmOpts};
@@ -91,9 +95,9 @@ pub async fn run(mut self) -> Result<()> {
         // Set fuzz seed so coverage reports are deterministic
         config.fuzz.seed = Some(U256::from_be_bytes(STATIC_FUZZ_SEED));
 
-        let (paths, mut output) = {
-            let (project, output) = self.build(&config)?;
-            (project.paths, output)
+        let (paths, mut output, rust_artifacts) = {
+            let (project, output, rust_artifacts) = self.build(&config)?;
+            (project.paths, output, rust_artifacts)
         };
 
         self.populate_reporters(&paths.root);
@@ -102,7 +106,7 @@ pub async fn run(mut self) -> Result<()> {
         let report = self.prepare(&paths, &mut output)?;
 
         sh_println!("Running tests...")?;
-        self.collect(&paths.root, &output, report, config, evm_opts).await
+        self.collect(&paths.root, &output, rust_artifacts, report, config, evm_opts).await
     }
 
     fn populate_reporters(&mut self, root: &Path) {
@@ -127,8 +131,11 @@ fn populate_reporters(&mut self, root: &Path) {
             .collect::<Vec<_>>();
     }
 
-    /// Builds the project.
-    fn build(&self, config: &Config) -> Result<(Project, ProjectCompileOutput)> {
+    /// Builds the project, returning the artifacts of its Rust contracts next to the output.
+    fn build(
+        &self,
+        config: &Config,
+    ) -> Result<(Project, ProjectCompileOutput, Vec<(ArtifactId, CompactContractBytecode)>)> {
         let mut project = config.ephemeral_project()?;
 
         if self.ir_minimum {
@@ -149,11 +156,14 @@ fn build(&self, config: &Config) -> Result<(Project, ProjectCompileOutput)> {
 
         config.disable_optimizations(&mut project, self.ir_minimum);
 
-        let output = ProjectCompiler::default()
-            .compile(&project)?
-            .with_stripped_file_prefixes(project.root());
+        let (output, rust_output) =
+            ProjectCompiler::default().config(config).compile_with_rust(&project)?;
+        let output = output.with_stripped_file_prefixes(project.root());
+        let rust_artifacts = rust_output
+            .map(|rust_output| compiled_rust_artifacts(&rust_output, project.artifacts_path()))
+            .unwrap_or_default();
 
-        Ok((project, output))
+        Ok((project, output, rust_artifacts))
     }
 
     /// Builds the coverage report.
@@ -243,13 +253,16 @@ async fn collect(
         mut self,
         project_root: &Path,
         output: &ProjectCompileOutput,
+        rust_artifacts: Vec<(ArtifactId, CompactContractBytecode)>,
         mut report: CoverageReport,
         config: Config,
         evm_opts: EvmOpts,
     ) -> Result<()> {
         let filter = self.test.filter(&config)?;
-        let outcome =
-            self.test.run_tests(project_root, config, evm_opts, output, &filter, true).await?;
+        let outcome = self
+            .test
+            .run_tests(project_root, config, evm_opts, output, rust_artifacts, &filter, true)
+            .await?;
 
         let known_contracts = outcome.runner.as_ref().unwrap().known_contracts.clone();
 
@@ -20,14 +20,12 @@
 use foundry_common::{
     compile::{self},
     fmt::parse_tokens,
-    rust_contracts::RustContractsRegistry,
-    shell,
+    rust_artifact::RustArtifact,
+    rust_contracts::{RustContractsFilter, RustContractsRegistry},
+    shell, wasm,
 };
 use foundry_compilers::{
-    ArtifactId,
-    artifacts::{BytecodeObject, CompactBytecode},
-    info::ContractInfo,
-    utils::canonicalize,
+    ArtifactId, artifacts::BytecodeObject, info::ContractInfo, utils::canonicalize,
 };
 use foundry_config::{
     Config,
@@ -39,16 +37,7 @@
 };
 use rand::{Rng, distributions::Alphanumeric};
 use serde_json::json;
-use std::{
-    borrow::{Borrow, Cow},
-    fs,
-    marker::PhantomData,
-    path::PathBuf,
-    sync::Arc,
-    time::Duration,
-};
-use wasm_encoder::{CustomSection, Module, RawSection};
-use wasmparser::Parser as WasmpParser;
+use std::{borrow::Borrow, marker::PhantomData, path::PathBuf, sync::Arc, time::Duration};
 
 fn generate_build_id() -> String {
     rand::thread_rng().sample_iter(&Alphanumeric).take(8).map(char::from).collect()
@@ -135,8 +124,11 @@ pub async fn run(mut self) -> Result<()> {
         let project = config.project()?;
 
         // Create Rust contracts registry
-        let rust_registry =
-            RustContractsRegistry::new(&project.paths.sources, Some(project.root()))?;
+        let rust_registry = RustContractsRegistry::with_filter(
+            &project.paths.sources,
+            Some(project.root()),
+            &RustContractsFilter::from_config(&config)?,
+        )?;
 
         // Determine target path
         let target_path = if let Some(ref mut path) = self.contract.path {
@@ -158,28 +150,20 @@ pub async fn run(mut self) -> Result<()> {
         // Load artifacts (either Rust or Solidity)
         let (abi, bin, id) = if let Some(info) = rust_registry.get(&self.contract.name) {
             // This is a Rust contract
-            let foundry_json_path = info.foundry_artifact_path(project.artifacts_path());
-
-            if !foundry_json_path.exists() {
-                eyre::bail!(
-                    "Artifact not found at {:?}\nPackage: {}\nContract input: {}\nDid you run 'gblend build'?",
-                    foundry_json_path,
-                    info.package_name,
-                    self.contract.name
-                );
-            }
+            let artifact = RustArtifact::load(info, project.artifacts_path())?;
             is_rust_contract = true;
 
-            sh_println!("Loading Rust artifact: {:?}", foundry_json_path)?;
-
-            let artifact: serde_json::Value =
-                serde_json::from_str(&fs::read_to_string(&foundry_json_path)?)?;
-
-            // Extract ABI
-            let abi: JsonAbi = serde_json::from_value(artifact["abi"].clone())?;
+            sh_println!("Loading Rust artifact: {:?}", artifact.dir)?;
 
-            // Extract Bytecode
-            let bin: CompactBytecode = serde_json::from_value(artifact["bytecode"].clone())?;
+            // Fluent rejects oversized wasm modules, only warn if we don't broadcast.
+            if let Err(err) = artifact.check_code_size() {
+                if self.broadcast {
+                    return Err(err);
+                }
+                sh_warn!("{err}")?;
+            }
+            let bin = artifact.compact_bytecode();
+            let abi = artifact.abi;
 
             // Create ArtifactId using package name from Cargo.toml
             let id = ArtifactId {
@@ -323,6 +307,7 @@ async fn verify_preflight_check(
             via_ir: self.build.via_ir,
             evm_version: self.build.compiler.evm_version,
             show_standard_json_input: self.show_standard_json_input,
+            show_archive: false,
             guess_constructor_args: false,
             compilation_profile: Some(id.profile.to_string()),
             language: None,
@@ -335,8 +320,7 @@ async fn verify_preflight_check(
         verify.etherscan.key =
             config.get_etherscan_config_with_chain(Some(chain.into()))?.map(|c| c.key);
         if self.verifier.wasm {
-            // TODO(d1r1): add actual checks - for now just return Ok(())
-            return Ok(());
+            verify.wasm_preflight_check().await?;
         } else {
             let context = verify.resolve_context().await?;
             verify.verification_provider()?.preflight_verify_check(verify, context).await?;
@@ -517,6 +501,7 @@ async fn deploy<P: Provider<AnyNetwork>>(
             via_ir: self.build.via_ir,
             evm_version: self.build.compiler.evm_version,
             show_standard_json_input: self.show_standard_json_input,
+            show_archive: false,
             guess_constructor_args: false,
             compilation_profile: Some(id.profile.to_string()),
             language: None,
@@ -666,7 +651,7 @@ pub fn deploy_tokens(
                     .map_err(ContractDeploymentError::DetokenizationError)?
                     .into();
                 if is_rust_contract {
-                    add_constructor_params_section(&self.bytecode, input)
+                    wasm::with_constructor_args(&self.bytecode, &input)
                 } else {
                     // Concatenate the bytecode and abi-encoded constructor call.
                     self.bytecode.iter().copied().chain(input).collect()
@@ -681,28 +666,6 @@ pub fn deploy_tokens(
     }
 }
 
-fn add_constructor_params_section(
-    input_wasm: impl AsRef<[u8]>,
-    constructor_args: impl AsRef<[u8]>,
-) -> Bytes {
-    let mut module = Module::new();
-
-    WasmpParser::new(0)
-        .parse_all(input_wasm.as_ref())
-        .flatten()
-        .filter_map(|payload| payload.as_section())
-        .for_each(|(id, range)| {
-            module.section(&RawSection { id, data: &input_wasm.as_ref()[range] });
-        });
-
-    module.section(&CustomSection {
-        name: Cow::Borrowed("input"),
-        data: Cow::Borrowed(constructor_args.as_ref()),
-    });
-
-    Bytes::from(module.finish())
-}
-
 #[derive(thiserror::Error, Debug)]
 /// An Error which is thrown when interacting with a smart contract
 pub enum ContractDeploymentError {
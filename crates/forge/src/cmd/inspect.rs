@@ -3,14 +3,23 @@
 use clap::Parser;
 use comfy_table::{Cell, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN};
 use eyre::{Result, eyre};
-use foundry_cli::opts::{BuildOpts, CompilerOpts};
+use foundry_cli::{
+    opts::{BuildOpts, CompilerOpts},
+    utils::LoadConfig,
+};
 use foundry_common::{
-    compile::{PathOrContractInfo, ProjectCompiler},
-    find_matching_contract_artifact, find_target_path, shell,
+    compile::{PathOrContractInfo, ProjectCompiler, rust_project},
+    find_matching_contract_artifact, find_target_path,
+    rust_artifact::{INTERFACE_ARTIFACT_FILENAME, RustArtifact},
+    rust_compiler::{RustWasmSettings, crate_sources},
+    rust_contracts::{RustContractsFilter, RustContractsRegistry, RustProjectInfo},
+    shell,
+    wasm::WasmModuleInfo,
 };
 use foundry_compilers::{
+    Project,
     artifacts::{
-        StorageLayout,
+        Source, StorageLayout,
         output_selection::{
             BytecodeOutputSelection, ContractOutputSelection, DeployedBytecodeOutputSelection,
             EvmOutputSelection, EwasmOutputSelection,
@@ -20,7 +29,7 @@
 };
 use regex::Regex;
 use serde_json::{Map, Value};
-use std::{collections::BTreeMap, fmt, str::FromStr, sync::LazyLock};
+use std::{collections::BTreeMap, fmt, fs, str::FromStr, sync::LazyLock};
 
 /// CLI arguments for `forge inspect`.
 #[derive(Clone, Debug, Parser)]
@@ -74,10 +83,34 @@ pub fn run(self) -> Result<()> {
             ..build
         };
 
+        let config = modified_build_args.load_config()?;
+        let project = config.project()?;
+
+        // Rust contracts are resolved by package name, a Solidity contract with the same name
+        // takes precedence unless the name has the `.wasm` suffix
+        let target_path = find_target_path(&project, &contract);
+        if let Some(name) = contract.name()
+            && (name.ends_with(".wasm") || target_path.is_err())
+        {
+            let filter = RustContractsFilter::from_config(&config)?;
+            let rust_registry = RustContractsRegistry::with_filter(
+                &project.paths.sources,
+                Some(project.root()),
+                &filter,
+            )?;
+            if let Some(info) = rust_registry.get(name) {
+                let settings = RustWasmSettings::from_config(&config);
+                let artifact = compile_rust_contract(&project, info, settings)?;
+                return print_rust_field(&artifact, field, wrap);
+            }
+        }
+        if field.is_rust_only() {
+            eyre::bail!("`{field}` is only available for Rust contracts");
+        }
+
         // Build the project
-        let project = modified_build_args.project()?;
-        let compiler = ProjectCompiler::new().quiet(true);
-        let target_path = find_target_path(&project, &contract)?;
+        let compiler = ProjectCompiler::new().config(&config).quiet(true);
+        let target_path = target_path?;
         let mut output = compiler.files([target_path.clone()]).compile(&project)?;
 
         // Find the artifact
@@ -175,6 +208,149 @@ pub fn run(self) -> Result<()> {
     }
 }
 
+/// Builds the Rust contract, if needed, and loads its artifacts.
+fn compile_rust_contract(
+    project: &Project,
+    info: &RustProjectInfo,
+    settings: RustWasmSettings,
+) -> Result<RustArtifact> {
+    let mut rust_project = rust_project(project, settings)?;
+    rust_project.compiler = rust_project.compiler.with_quiet(true);
+    let sources = Source::read_all(crate_sources(&info.path))?;
+    let output = foundry_compilers::project::ProjectCompiler::with_sources(&rust_project, sources)?
+        .compile()?;
+    if output.has_compiler_errors() {
+        eyre::bail!("{output}");
+    }
+    RustArtifact::load(info, project.artifacts_path())
+}
+
+/// Prints a field of a Rust contract.
+fn print_rust_field(
+    artifact: &RustArtifact,
+    field: ContractArtifactField,
+    wrap: bool,
+) -> Result<()> {
+    type Caf = ContractArtifactField;
+    match field {
+        Caf::Abi => print_abi(&artifact.abi, wrap),
+        Caf::Bytecode => print_json_str(&artifact.bytecode, None),
+        Caf::DeployedBytecode => print_json_str(artifact.rwasm()?, None),
+        Caf::MethodIdentifiers => {
+            print_method_identifiers(&Some(artifact.method_identifiers.clone()), wrap)
+        }
+        Caf::Errors => print_errors_events(&parse_errors(&artifact.abi), true, wrap),
+        Caf::Events => print_errors_events(&parse_events(&artifact.abi), false, wrap),
+        Caf::Metadata => print_json(artifact.metadata()?),
+        Caf::Interface => {
+            let path = artifact.interface.as_ref().ok_or_else(|| {
+                eyre!(
+                    "{INTERFACE_ARTIFACT_FILENAME} of Rust contract '{}' not found, build it with \
+                     the `solidity` artifact",
+                    artifact.name
+                )
+            })?;
+            sh_print!("{}", fs::read_to_string(path)?)?;
+            Ok(())
+        }
+        Caf::SdkVersion => print_json_str(&artifact.sdk_version()?, None),
+        Caf::BuildSettings => print_json(&artifact.build_config()?),
+        Caf::WasmImports => print_wasm_imports(&WasmModuleInfo::parse(&artifact.bytecode)?, wrap),
+        Caf::WasmExports => print_wasm_exports(&WasmModuleInfo::parse(&artifact.bytecode)?, wrap),
+        Caf::WasmLimits => print_wasm_limits(&WasmModuleInfo::parse(&artifact.bytecode)?, wrap),
+        Caf::WasmSections => print_wasm_sections(&WasmModuleInfo::parse(&artifact.bytecode)?, wrap),
+        _ => Err(eyre!("`{field}` is not available for Rust contracts")),
+    }
+}
+
+fn print_wasm_imports(module: &WasmModuleInfo, should_wrap: bool) -> Result<()> {
+    let imports = module.imported_functions().collect::<Vec<_>>();
+    if shell::is_json() {
+        return print_json(&imports);
+    }
+
+    let headers = vec![Cell::new("Module"), Cell::new("Function"), Cell::new("Signature")];
+    print_table(
+        headers,
+        |table| {
+            for import in imports {
+                table.add_row([
+                    import.module.as_str(),
+                    import.name.as_str(),
+                    import.signature.as_deref().unwrap_or("?"),
+                ]);
+            }
+        },
+        should_wrap,
+    )
+}
+
+fn print_wasm_exports(module: &WasmModuleInfo, should_wrap: bool) -> Result<()> {
+    let exports = module.exported_functions().collect::<Vec<_>>();
+    if shell::is_json() {
+        return print_json(&exports);
+    }
+
+    let headers = vec![Cell::new("Function"), Cell::new("Signature")];
+    print_table(
+        headers,
+        |table| {
+            for export in exports {
+                table.add_row([export.name.as_str(), export.signature.as_deref().unwrap_or("?")]);
+            }
+        },
+        should_wrap,
+    )
+}
+
+fn print_wasm_limits(module: &WasmModuleInfo, should_wrap: bool) -> Result<()> {
+    if shell::is_json() {
+        return print_json(&serde_json::json!({
+            "memories": module.memories,
+            "tables": module.tables,
+        }));
+    }
+
+    let headers =
+        vec![Cell::new("Kind"), Cell::new("Imported"), Cell::new("Initial"), Cell::new("Maximum")];
+    print_table(
+        headers,
+        |table| {
+            let rows = module
+                .memories
+                .iter()
+                .map(|limits| ("memory (pages)", limits))
+                .chain(module.tables.iter().map(|limits| ("table (elements)", limits)));
+            for (kind, limits) in rows {
+                table.add_row([
+                    kind.to_string(),
+                    limits.imported.to_string(),
+                    limits.initial.to_string(),
+                    limits.maximum.map_or_else(|| "none".to_string(), |max| max.to_string()),
+                ]);
+            }
+        },
+        should_wrap,
+    )
+}
+
+fn print_wasm_sections(module: &WasmModuleInfo, should_wrap: bool) -> Result<()> {
+    if shell::is_json() {
+        return print_json(&module.sections);
+    }
+
+    let headers = vec![Cell::new("Section"), Cell::new("Size (B)")];
+    print_table(
+        headers,
+        |table| {
+            for section in &module.sections {
+                table.add_row([section.name.clone(), section.size.to_string()]);
+            }
+        },
+        should_wrap,
+    )
+}
+
 fn parse_errors(abi: &JsonAbi) -> Map<String, Value> {
     let mut out = serde_json::Map::new();
     for er in abi.errors.iter().flat_map(|(_, errors)| errors) {
@@ -428,6 +604,13 @@ pub enum ContractArtifactField {
     Events,
     StandardJson,
     Libraries,
+    Interface,
+    SdkVersion,
+    BuildSettings,
+    WasmImports,
+    WasmExports,
+    WasmLimits,
+    WasmSections,
 }
 
 macro_rules! impl_value_enum {
@@ -488,9 +671,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
 impl_value_enum! {
     enum ContractArtifactField {
         Abi               => "abi",
-        Bytecode          => "bytecode" | "bytes" | "b",
+        Bytecode          => "bytecode" | "bytes" | "b" | "wasm",
         DeployedBytecode  => "deployedBytecode" | "deployed_bytecode" | "deployed-bytecode"
-                             | "deployed" | "deployedbytecode",
+                             | "deployed" | "deployedbytecode" | "rwasm" | "rWasm",
         Assembly          => "assembly" | "asm",
         LegacyAssembly    => "legacyAssembly" | "legacyassembly" | "legacy_assembly",
         AssemblyOptimized => "assemblyOptimized" | "asmOptimized" | "assemblyoptimized"
@@ -512,6 +695,15 @@ enum ContractArtifactField {
         Events            => "events" | "ev",
         StandardJson      => "standardJson" | "standard-json" | "standard_json",
         Libraries         => "libraries" | "lib" | "libs",
+        Interface         => "interface" | "solidityInterface" | "solidity-interface"
+                             | "solidity_interface",
+        SdkVersion        => "sdkVersion" | "sdk-version" | "sdk_version" | "sdk",
+        BuildSettings     => "buildSettings" | "build-settings" | "build_settings"
+                             | "buildConfig" | "build-config" | "build_config",
+        WasmImports       => "wasmImports" | "wasm-imports" | "wasm_imports" | "imports",
+        WasmExports       => "wasmExports" | "wasm-exports" | "wasm_exports" | "exports",
+        WasmLimits        => "wasmLimits" | "wasm-limits" | "wasm_limits" | "limits",
+        WasmSections      => "wasmSections" | "wasm-sections" | "wasm_sections" | "sections",
     }
 }
 
@@ -545,6 +737,13 @@ fn try_from(field: ContractArtifactField) -> Result<Self, Self::Error> {
                 Err(eyre!("StandardJson is not supported for ContractOutputSelection"))
             }
             Caf::Libraries => Err(eyre!("Libraries is not supported for ContractOutputSelection")),
+            field @ (Caf::Interface
+            | Caf::SdkVersion
+            | Caf::BuildSettings
+            | Caf::WasmImports
+            | Caf::WasmExports
+            | Caf::WasmLimits
+            | Caf::WasmSections) => Err(eyre!("{field} is only available for Rust contracts")),
         }
     }
 }
@@ -586,6 +785,20 @@ pub const fn can_skip_field(&self) -> bool {
         matches!(
             self,
             Self::Bytecode | Self::DeployedBytecode | Self::StandardJson | Self::Libraries
+        ) || self.is_rust_only()
+    }
+
+    /// Returns true if this field is only available for Rust contracts.
+    pub const fn is_rust_only(&self) -> bool {
+        matches!(
+            self,
+            Self::Interface
+                | Self::SdkVersion
+                | Self::BuildSettings
+                | Self::WasmImports
+                | Self::WasmExports
+                | Self::WasmLimits
+                | Self::WasmSections
         )
     }
 }
@@ -662,6 +875,12 @@ fn contract_output_selection() {
                         .to_string()
                         .eq("Libraries is not supported for ContractOutputSelection")
                 );
+            } else if field.is_rust_only() {
+                let selection: Result<ContractOutputSelection, _> = field.try_into();
+                assert_eq!(
+                    selection.unwrap_err().to_string(),
+                    format!("{field} is only available for Rust contracts")
+                );
             } else {
                 let selection: ContractOutputSelection = field.try_into().unwrap();
                 assert_eq!(field, selection);
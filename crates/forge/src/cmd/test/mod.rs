@@ -20,10 +20,13 @@
     opts::{BuildOpts, EvmArgs, GlobalArgs},
     utils::{self, LoadConfig},
 };
-use foundry_common::{EmptyTestFilter, TestFunctionExt, compile::ProjectCompiler, fs, shell};
+use foundry_common::{
+    EmptyTestFilter, TestFunctionExt, compile::ProjectCompiler, fs,
+    rust_artifact::compiled_rust_artifacts, shell,
+};
 use foundry_compilers::{
-    ProjectCompileOutput,
-    artifacts::output_selection::OutputSelection,
+    ArtifactId, ProjectCompileOutput,
+    artifacts::{CompactContractBytecode, output_selection::OutputSelection},
     compilers::{
         Language,
         multi::{MultiCompiler, MultiCompilerLanguage},
@@ -278,22 +281,36 @@ pub async fn compile_and_run(&mut self) -> Result<TestOutcome> {
             .dynamic_test_linking(config.dynamic_test_linking)
             .quiet(shell::is_json() || self.junit)
             .files(self.get_sources_to_compile(&config, &filter)?)
-            .no_docker(config.no_docker);
-
-        let output = compiler.compile(&project)?;
-
-        self.run_tests(&project.paths.root, config, evm_opts, &output, &filter, false).await
+            .config(&config);
+
+        let (output, rust_output) = compiler.compile_with_rust(&project)?;
+        let rust_artifacts = rust_output
+            .map(|rust_output| compiled_rust_artifacts(&rust_output, project.artifacts_path()))
+            .unwrap_or_default();
+
+        self.run_tests(
+            &project.paths.root,
+            config,
+            evm_opts,
+            &output,
+            rust_artifacts,
+            &filter,
+            false,
+        )
+        .await
     }
 
     /// Executes all the tests in the project.
     ///
     /// See [`Self::compile_and_run`] for more details.
+    #[expect(clippy::too_many_arguments)]
     pub async fn run_tests(
         &mut self,
         project_root: &Path,
         mut config: Config,
         mut evm_opts: EvmOpts,
         output: &ProjectCompileOutput,
+        rust_artifacts: Vec<(ArtifactId, CompactContractBytecode)>,
         filter: &ProjectPathsAwareFilter,
         coverage: bool,
     ) -> Result<TestOutcome> {
@@ -344,6 +361,7 @@ pub async fn run_tests(
             .enable_isolation(evm_opts.isolate)
             .networks(evm_opts.networks)
             .fail_fast(self.fail_fast)
+            .rust_artifacts(rust_artifacts)
             .set_coverage(coverage)
             .build::<MultiCompiler>(output, env, evm_opts)?;
 
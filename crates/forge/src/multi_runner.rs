@@ -14,7 +14,7 @@
 };
 use foundry_compilers::{
     Artifact, ArtifactId, ProjectCompileOutput,
-    artifacts::{Contract, Libraries},
+    artifacts::{CompactContractBytecode, Contract, Libraries},
     compilers::Compiler,
 };
 
@@ -182,9 +182,8 @@ pub fn test(
     ) -> Result<()> {
         let tokio_handle = tokio::runtime::Handle::current();
         trace!("running all tests");
-        //     ,  self.tcfg.evm_opts.genesis.clone()
         // The DB backend that serves all the data.
-        let db = Backend::spawn(self.fork.take())?;
+        let db = Backend::spawn_with_genesis(self.fork.take(), self.tcfg.evm_opts.load_genesis()?)?;
 
         let find_timer = Instant::now();
         let contracts = self.matching_contracts(filter).collect::<Vec<_>>();
@@ -429,6 +428,8 @@ pub struct MultiContractRunnerBuilder {
     pub networks: NetworkConfigs,
     /// Whether to exit early on test failure.
     pub fail_fast: bool,
+    /// Artifacts of the built Rust contracts, added to the known contracts.
+    pub rust_artifacts: Vec<(ArtifactId, CompactContractBytecode)>,
 }
 
 impl MultiContractRunnerBuilder {
@@ -445,6 +446,7 @@ pub fn new(config: Arc<Config>) -> Self {
             decode_internal: Default::default(),
             networks: Default::default(),
             fail_fast: false,
+            rust_artifacts: Default::default(),
         }
     }
 
@@ -498,6 +500,14 @@ pub fn networks(mut self, networks: NetworkConfigs) -> Self {
         self
     }
 
+    pub fn rust_artifacts(
+        mut self,
+        artifacts: impl IntoIterator<Item = (ArtifactId, CompactContractBytecode)>,
+    ) -> Self {
+        self.rust_artifacts = artifacts.into_iter().collect();
+        self
+    }
+
     /// Given an EVM, proceeds to return a runner which is able to execute all tests
     /// against that evm
     pub fn build<C: Compiler<CompilerContract = Contract>>(
@@ -517,7 +527,8 @@ pub fn build<C: Compiler<CompilerContract = Contract>>(
         let abis = linker
             .contracts
             .iter()
-            .filter_map(|(_, contract)| contract.abi.as_ref().map(|abi| abi.borrow()));
+            .filter_map(|(_, contract)| contract.abi.as_ref().map(|abi| abi.borrow()))
+            .chain(self.rust_artifacts.iter().filter_map(|(_, contract)| contract.abi.as_ref()));
         let revert_decoder = RevertDecoder::new().with_abis(abis);
 
         let LinkOutput { libraries, libs_to_deploy } = linker.link_with_nonce_or_address(
@@ -553,8 +564,12 @@ pub fn build<C: Compiler<CompilerContract = Contract>>(
         }
 
         // Create known contracts from linked contracts and storage layout information (if any).
-        let known_contracts =
-            ContractsByArtifactBuilder::new(linked_contracts).with_output(output, root).build();
+        // Rust contracts are built outside of the Solidity compile output, add their artifacts so
+        // traces and cheatcodes can identify and deploy them.
+        let known_contracts = ContractsByArtifactBuilder::new(linked_contracts)
+            .with_artifacts(self.rust_artifacts)
+            .with_output(output, root)
+            .build();
 
         // Initialize and configure the solar compiler.
         let mut analysis = solar::sema::Compiler::new(
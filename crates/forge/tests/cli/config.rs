@@ -369,6 +369,8 @@
         _non_exhaustive: (),
         wasm: false,
         no_docker: false,
+        rust: Default::default(),
+        fluent_genesis: Default::default(),
     };
     prj.write_config(input.clone());
     let config = cmd.config();
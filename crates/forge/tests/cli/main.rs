@@ -26,6 +26,7 @@
 mod lint;
 mod multi_script;
 mod precompiles;
+mod rust;
 mod script;
 mod soldeer;
 mod svm;
@@ -0,0 +1,157 @@
+//! Tests for Rust contracts.
+
+use foundry_test_utils::util::{OutputExt, TestProject};
+use std::fs;
+
+const POWER_CALCULATOR_CARGO_TOML: &str =
+    include_str!("../../../../testdata/src/power-calculator/Cargo.toml");
+const POWER_CALCULATOR: &str = include_str!("../../../../testdata/src/power-calculator/src/lib.rs");
+
+/// A contract which can only be deployed with `42` as its constructor argument.
+const CONSTRUCTOR_CHECK: &str = r#"
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+extern crate alloc;
+extern crate fluentbase_sdk;
+
+use fluentbase_sdk::{
+    basic_entrypoint,
+    derive::{constructor, router, Contract},
+    SharedAPI, U256,
+};
+
+#[derive(Contract, Default)]
+struct ConstructorCheck<SDK> {
+    sdk: SDK,
+}
+
+pub trait ConstructorCheckAPI {
+    fn ping(&self) -> U256;
+}
+
+#[router(mode = "solidity")]
+impl<SDK: SharedAPI> ConstructorCheckAPI for ConstructorCheck<SDK> {
+    fn ping(&self) -> U256 {
+        U256::from(1)
+    }
+}
+
+#[constructor(mode = "solidity")]
+impl<SDK: SharedAPI> ConstructorCheck<SDK> {
+    pub fn constructor(&mut self, value: U256) {
+        assert_eq!(value, U256::from(42));
+    }
+}
+
+basic_entrypoint!(ConstructorCheck);
+"#;
+
+/// Adds the Rust contract `package` with the given `lib.rs` to the source directory, built against
+/// the SDK of the `power-calculator` test contract.
+fn add_rust_contract(prj: &TestProject, package: &str, lib: &str) {
+    let dir = prj.paths().sources.join(package);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        POWER_CALCULATOR_CARGO_TOML.replace("power-calculator", package),
+    )
+    .unwrap();
+    fs::write(dir.join("src/lib.rs"), lib).unwrap();
+}
+
+// Rust contracts are resolved by their package name in the code cheatcodes.
+forgetest!(rust_contract_code_cheatcodes, |prj, cmd| {
+    prj.insert_utils();
+    add_rust_contract(&prj, "power-calculator", POWER_CALCULATOR);
+    add_rust_contract(&prj, "constructor-check", CONSTRUCTOR_CHECK);
+    prj.add_test(
+        "RustCheatcodes.t.sol",
+        r#"
+import "./utils/Test.sol";
+
+interface IPowerCalculator {
+    function power(uint256 base, uint256 exponent) external view returns (uint256);
+}
+
+interface IConstructorCheck {
+    function ping() external view returns (uint256);
+}
+
+contract RustCheatcodesTest is Test {
+    function testDeployCode() public {
+        IPowerCalculator calculator = IPowerCalculator(vm.deployCode("PowerCalculator.wasm"));
+        assertEq(calculator.power(2, 10), 1024);
+    }
+
+    function testDeployCodeWithConstructorArgs() public {
+        IConstructorCheck check =
+            IConstructorCheck(vm.deployCode("ConstructorCheck.wasm", abi.encode(uint256(42))));
+        assertEq(check.ping(), 1);
+    }
+
+    function testGetCode() public {
+        bytes memory code = vm.getCode("power-calculator");
+        assertEq(bytes4(code), bytes4(hex"0061736d"));
+        assertEq(vm.getCode("PowerCalculator.wasm"), code);
+    }
+
+    function testGetDeployedCode() public {
+        address calculator = vm.deployCode("power-calculator");
+        bytes memory code = vm.getDeployedCode("PowerCalculator.wasm");
+        assertEq(bytes2(code), bytes2(hex"ef52"));
+        assertEq(vm.getDeployedCode("power-calculator"), code);
+        assertEq(calculator.code, code);
+    }
+
+    function testEtchWasm() public {
+        address target = address(0x1234);
+        vm.etchWasm(target, "PowerCalculator.wasm");
+        assertEq(target.code, vm.getDeployedCode("power-calculator"));
+        assertEq(IPowerCalculator(target).power(3, 3), 27);
+    }
+}
+"#,
+    );
+
+    cmd.args(["test", "--mc", "RustCheatcodesTest"]).assert_success();
+});
+
+// Calls into Rust contracts are reported like calls into Solidity contracts.
+forgetest!(rust_contract_gas_report, |prj, cmd| {
+    prj.insert_utils();
+    add_rust_contract(&prj, "power-calculator", POWER_CALCULATOR);
+    prj.add_test(
+        "RustGasReport.t.sol",
+        r#"
+import "./utils/Test.sol";
+
+interface IPowerCalculator {
+    function power(uint256 base, uint256 exponent) external view returns (uint256);
+}
+
+contract RustGasReportTest is Test {
+    function testPower() public {
+        IPowerCalculator calculator = IPowerCalculator(vm.deployCode("PowerCalculator.wasm"));
+        assertEq(calculator.power(2, 10), 1024);
+        assertEq(calculator.power(3, 3), 27);
+    }
+}
+"#,
+    );
+
+    let output = cmd
+        .args(["test", "--mc", "RustGasReportTest", "--gas-report", "--json"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let contracts = report.as_array().unwrap();
+    let calculator = contracts
+        .iter()
+        .find(|contract| contract["functions"].get("power(uint256,uint256)").is_some())
+        .unwrap_or_else(|| panic!("no gas report of the Rust contract: {output}"));
+    assert!(calculator["deployment"]["gas"].as_u64().unwrap() > 0, "{output}");
+    let power = &calculator["functions"]["power(uint256,uint256)"];
+    assert_eq!(power["calls"], 2, "{output}");
+    assert!(power["min"].as_u64().unwrap() > 0, "{output}");
+});
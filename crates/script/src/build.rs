@@ -8,10 +8,11 @@
 use forge_script_sequence::ScriptSequence;
 use foundry_cheatcodes::Wallets;
 use foundry_common::{
-    compile::ProjectCompiler, provider::try_get_http_provider, ContractData, ContractsByArtifact,
+    compile::ProjectCompiler, provider::try_get_http_provider,
+    rust_artifact::compiled_rust_artifacts, ContractData, ContractsByArtifact,
 };
 use foundry_compilers::{
-    artifacts::{BytecodeObject, Libraries},
+    artifacts::{BytecodeObject, CompactContractBytecode, Libraries},
     compilers::{multi::MultiCompilerLanguage, Language},
     info::ContractInfo,
     utils::source_files_iter,
@@ -30,6 +31,8 @@ pub struct BuildData {
     pub output: ProjectCompileOutput,
     /// ID of target contract artifact.
     pub target: ArtifactId,
+    /// Artifacts of the built Rust contracts, which aren't part of the compiler output.
+    pub rust_artifacts: Vec<(ArtifactId, CompactContractBytecode)>,
 }
 
 impl BuildData {
@@ -150,8 +153,13 @@ pub fn new(
             Some(&libraries),
         )?;
 
-        let known_contracts =
-            ContractsByArtifact::new(build_data.get_linker().get_linked_artifacts(&libraries)?);
+        let known_contracts = ContractsByArtifact::new(
+            build_data
+                .get_linker()
+                .get_linked_artifacts(&libraries)?
+                .into_iter()
+                .chain(build_data.rust_artifacts.iter().cloned()),
+        );
 
         Ok(Self { build_data, known_contracts, libraries, predeploy_libraries, sources })
     }
@@ -202,7 +210,14 @@ pub fn compile(self) -> Result<CompiledState> {
         )
         .chain([target_path.to_path_buf()]);
 
-        let output = ProjectCompiler::new().files(sources_to_compile).compile(&project)?;
+        let config = &script_config.config;
+        let (output, rust_output) = ProjectCompiler::new()
+            .files(sources_to_compile)
+            .config(config)
+            .compile_with_rust(&project)?;
+        let rust_artifacts = rust_output
+            .map(|rust_output| compiled_rust_artifacts(&rust_output, project.artifacts_path()))
+            .unwrap_or_default();
 
         let mut target_id: Option<ArtifactId> = None;
 
@@ -244,7 +259,12 @@ pub fn compile(self) -> Result<CompiledState> {
             args,
             script_config,
             script_wallets,
-            build_data: BuildData { output, target, project_root: project.root().to_path_buf() },
+            build_data: BuildData {
+                output,
+                target,
+                project_root: project.root().to_path_buf(),
+                rust_artifacts,
+            },
         })
     }
 }
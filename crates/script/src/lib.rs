@@ -33,6 +33,7 @@
     CONTRACT_MAX_SIZE, ContractsByArtifact, SELECTOR_LEN,
     abi::{encode_function_args, get_func},
     shell,
+    wasm::{check_code_size, check_rwasm_size, is_wasm},
 };
 use foundry_compilers::ArtifactId;
 use foundry_config::{
@@ -398,7 +399,8 @@ fn get_method_and_calldata(&self, abi: &JsonAbi) -> Result<(Function, Bytes)> {
     }
 
     /// Checks if the transaction is a deployment with either a size above the `CONTRACT_MAX_SIZE`
-    /// or specified `code_size_limit`.
+    /// or specified `code_size_limit`, or a wasm deployment above Fluent's wasm or rWasm size
+    /// limits.
     ///
     /// If `self.broadcast` is enabled, it asks confirmation of the user. Otherwise, it just warns
     /// the user.
@@ -444,13 +446,10 @@ fn check_contract_sizes(
             None => CONTRACT_MAX_SIZE,
         };
 
+        // Wasm deployments are checked against Fluent's limits, which don't depend on `max_size`.
         for (data, to) in result.transactions.iter().flat_map(|txes| {
-            txes.iter().filter_map(|tx| {
-                tx.transaction
-                    .input()
-                    .filter(|data| data.len() > max_size)
-                    .map(|data| (data, tx.transaction.to()))
-            })
+            txes.iter()
+                .filter_map(|tx| tx.transaction.input().map(|data| (data, tx.transaction.to())))
         }) {
             let mut offset = 0;
 
@@ -466,10 +465,23 @@ fn check_contract_sizes(
                 // Pass
             }
 
+            let Some(code) = data.get(offset..) else { continue };
+
             // Find artifact with a deployment code same as the data.
-            if let Some((name, _, deployed_code)) =
-                bytecodes.iter().find(|(_, init_code, _)| *init_code == &data[offset..])
+            if let Some((name, init_code, deployed_code)) =
+                bytecodes.iter().find(|(_, init_code, _)| *init_code == code)
             {
+                if is_wasm(init_code) {
+                    if let Err(err) = check_code_size(name, init_code.len()) {
+                        prompt_user = self.should_broadcast();
+                        sh_err!("{err}.")?;
+                    }
+                    if let Some(warning) = check_rwasm_size(name, deployed_code.len()) {
+                        sh_warn!("{warning}.")?;
+                    }
+                    continue;
+                }
+
                 let deployment_size = deployed_code.len();
 
                 if deployment_size > max_size {
@@ -550,8 +562,11 @@ pub fn get_created_contracts(
                 traces.nodes().iter().filter_map(|node| {
                     if node.trace.kind.is_any_create() {
                         let init_code = node.trace.data.clone();
+                        // Rust contracts are matched exactly, the constructor arguments
+                        // section would skew the similarity score
                         let contract_name = known_contracts
-                            .find_by_creation_code(init_code.as_ref())
+                            .find_rust_contract(init_code.as_ref())
+                            .or_else(|| known_contracts.find_by_creation_code(init_code.as_ref()))
                             .map(|artifact| artifact.0.name.clone());
                         return Some(AdditionalContract {
                             opcode: node.trace.kind,
@@ -634,7 +649,7 @@ async fn _get_runner(
                 Some(db) => db.clone(),
                 None => {
                     let fork = self.evm_opts.get_fork(&self.config, env.clone());
-                    let backend = Backend::spawn(fork)?;
+                    let backend = Backend::spawn_with_genesis(fork, self.evm_opts.load_genesis()?)?;
                     self.backends.insert(fork_url.clone(), backend.clone());
                     backend
                 }
@@ -643,7 +658,7 @@ async fn _get_runner(
             // It's only really `None`, when we don't pass any `--fork-url`. And if so, there is
             // no need to cache it, since there won't be any onchain simulation that we'd need
             // to cache the backend for.
-            Backend::spawn(None)?
+            Backend::spawn_with_genesis(None, self.evm_opts.load_genesis()?)?
         };
 
         // We need to enable tracing to decode contract names: local or external.
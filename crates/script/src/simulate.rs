@@ -68,11 +68,17 @@ pub async fn fill_metadata(self) -> Result<FilledTransactionsState> {
                 if let Some(TxKind::Call(_)) = to {
                     builder.set_call(
                         &address_to_abi,
+                        &self.build_data.known_contracts,
                         &self.execution_artifacts.decoder,
                         self.script_config.evm_opts.create2_deployer,
                     )?;
                 } else {
-                    builder.set_create(false, sender.create(nonce), &address_to_abi)?;
+                    builder.set_create(
+                        false,
+                        sender.create(nonce),
+                        &address_to_abi,
+                        &self.build_data.known_contracts,
+                    )?;
                 }
 
                 Ok(builder.build())
@@ -1,10 +1,13 @@
 use super::ScriptResult;
 use crate::build::LinkedBuildData;
 use alloy_dyn_abi::JsonAbiExt;
-use alloy_primitives::{Address, B256, TxKind, hex};
+use alloy_primitives::{Address, B256, Bytes, TxKind, hex};
 use eyre::Result;
 use forge_script_sequence::TransactionWithMetadata;
-use foundry_common::{ContractData, SELECTOR_LEN, TransactionMaybeSigned, fmt::format_token_raw};
+use foundry_common::{
+    ContractData, ContractsByArtifact, SELECTOR_LEN, TransactionMaybeSigned, fmt::format_token_raw,
+    wasm,
+};
 use foundry_evm::traces::CallTraceDecoder;
 use itertools::Itertools;
 use revm_inspectors::tracing::types::CallKind;
@@ -29,6 +32,7 @@ pub fn new(transaction: TransactionMaybeSigned, rpc: String) -> Self {
     pub fn set_call(
         &mut self,
         local_contracts: &BTreeMap<Address, &ContractData>,
+        known_contracts: &ContractsByArtifact,
         decoder: &CallTraceDecoder,
         create2_deployer: Address,
     ) -> Result<()> {
@@ -41,6 +45,7 @@ pub fn set_call(
                         true,
                         create2_deployer.create2_from_code(B256::from_slice(salt), init_code),
                         local_contracts,
+                        known_contracts,
                     )?;
                 }
             } else {
@@ -89,53 +94,61 @@ pub fn set_call(
     ///
     /// If this is a CREATE2 transaction this attempt to decode the arguments from the CREATE2
     /// deployer's function
+    ///
+    /// Rust contracts which weren't identified from the traces are looked up in
+    /// `known_contracts` by their init code, see [`ContractsByArtifact::find_rust_contract`].
     pub fn set_create(
         &mut self,
         is_create2: bool,
         address: Address,
         contracts: &BTreeMap<Address, &ContractData>,
+        known_contracts: &ContractsByArtifact,
     ) -> Result<()> {
         if is_create2 {
             self.transaction.opcode = CallKind::Create2;
         } else {
             self.transaction.opcode = CallKind::Create;
         }
+        self.transaction.contract_address = Some(address);
 
-        let info = contracts.get(&address);
+        // `create2` transactions are prefixed by a 32 byte salt.
+        let creation_code = self
+            .transaction
+            .transaction
+            .input()
+            .and_then(|data| if is_create2 { data.get(32..) } else { Some(&data[..]) });
+
+        let info = contracts.get(&address).copied().or_else(|| {
+            known_contracts.find_rust_contract(creation_code?).map(|(_, contract)| contract)
+        });
         self.transaction.contract_name = info.map(|info| info.name.clone());
-        self.transaction.contract_address = Some(address);
 
-        let Some(data) = self.transaction.transaction.input() else { return Ok(()) };
-        let Some(info) = info else { return Ok(()) };
+        let (Some(creation_code), Some(info)) = (creation_code, info) else { return Ok(()) };
         let Some(bytecode) = info.bytecode() else { return Ok(()) };
 
-        // `create2` transactions are prefixed by a 32 byte salt.
-        let creation_code = if is_create2 {
-            if data.len() < 32 {
+        let constructor_args = if wasm::is_wasm(creation_code) {
+            // Wasm deployments pass the constructor args in a custom section.
+            let Ok(Some(args)) = wasm::constructor_args(creation_code) else { return Ok(()) };
+            args
+        } else {
+            // The constructor args start after bytecode.
+            let contains_constructor_args = creation_code.len() > bytecode.len();
+            if !contains_constructor_args {
                 return Ok(());
             }
-            &data[32..]
-        } else {
-            data
+            Bytes::copy_from_slice(&creation_code[bytecode.len()..])
         };
 
-        // The constructor args start after bytecode.
-        let contains_constructor_args = creation_code.len() > bytecode.len();
-        if !contains_constructor_args {
-            return Ok(());
-        }
-        let constructor_args = &creation_code[bytecode.len()..];
-
         let Some(constructor) = info.abi.constructor() else { return Ok(()) };
-        let values = constructor.abi_decode_input(constructor_args).inspect_err(|_| {
+        let values = constructor.abi_decode_input(&constructor_args).inspect_err(|_| {
                 error!(
                     contract=?self.transaction.contract_name,
                     signature=%format!("constructor({})", constructor.inputs.iter().map(|p| &p.ty).format(",")),
                     is_create2,
-                    constructor_args=%hex::encode(constructor_args),
+                    constructor_args=%hex::encode(&constructor_args),
                     "Failed to decode constructor arguments",
                 );
-                debug!(full_data=%hex::encode(data), bytecode=%hex::encode(creation_code));
+                debug!(creation_code=%hex::encode(creation_code), bytecode=%hex::encode(bytecode));
             })?;
         self.transaction.arguments = Some(values.iter().map(format_token_raw).collect());
 
@@ -8,8 +8,8 @@
 use forge_script_sequence::{AdditionalContract, ScriptSequence};
 use forge_verify::{RetryArgs, VerifierArgs, VerifyArgs, provider::VerificationProviderType};
 use foundry_cli::opts::{EtherscanOpts, ProjectPathOpts};
-use foundry_common::ContractsByArtifact;
-use foundry_compilers::{Project, artifacts::EvmVersion, info::ContractInfo};
+use foundry_common::{ContractsByArtifact, rust_artifact::is_rust_artifact, wasm};
+use foundry_compilers::{ArtifactId, Project, artifacts::EvmVersion, info::ContractInfo};
 use foundry_config::{Chain, Config};
 use semver::Version;
 
@@ -110,10 +110,18 @@ pub fn get_verify_args(
         libraries: &[String],
         evm_version: EvmVersion,
     ) -> Option<VerifyArgs> {
+        // If it's a CREATE2, the tx.data comes with a 32-byte salt in the beginning
+        // of the transaction
+        let init_code = data.get(create2_offset..)?;
+        if let Some((artifact, _)) = self.known_contracts.find_rust_contract(init_code) {
+            return Some(self.get_wasm_verify_args(contract_address, artifact, init_code));
+        }
+
         for (artifact, contract) in self.known_contracts.iter() {
+            if is_rust_artifact(artifact) {
+                continue;
+            }
             let Some(bytecode) = contract.bytecode() else { continue };
-            // If it's a CREATE2, the tx.data comes with a 32-byte salt in the beginning
-            // of the transaction
             if data.split_at(create2_offset).1.starts_with(bytecode) {
                 let constructor_args = data.split_at(create2_offset + bytecode.len()).1.to_vec();
 
@@ -162,6 +170,7 @@ pub fn get_verify_args(
                     via_ir: self.via_ir,
                     evm_version: Some(evm_version),
                     show_standard_json_input: false,
+                    show_archive: false,
                     guess_constructor_args: false,
                     compilation_profile: Some(artifact.profile.to_string()),
                     language: None,
@@ -173,6 +182,48 @@ pub fn get_verify_args(
         }
         None
     }
+
+    /// Returns the `VerifyArgs` of a Rust contract deployed with `init_code`, which are verified
+    /// through the WASM verification flow.
+    fn get_wasm_verify_args(
+        &self,
+        contract_address: Address,
+        artifact: &ArtifactId,
+        init_code: &[u8],
+    ) -> VerifyArgs {
+        let constructor_args = wasm::constructor_args(init_code).ok().flatten();
+        let mut verifier = self.verifier.clone();
+        verifier.wasm = true;
+
+        VerifyArgs {
+            address: contract_address,
+            contract: Some(ContractInfo { path: None, name: artifact.name.clone() }),
+            compiler_version: None,
+            constructor_args: constructor_args.map(hex::encode),
+            constructor_args_path: None,
+            no_auto_detect: false,
+            use_solc: None,
+            num_of_optimizations: None,
+            etherscan: self.etherscan.clone(),
+            rpc: Default::default(),
+            flatten: false,
+            force: false,
+            skip_is_verified_check: true,
+            watch: true,
+            retry: self.retry,
+            libraries: vec![],
+            root: None,
+            verifier,
+            via_ir: false,
+            evm_version: None,
+            show_standard_json_input: false,
+            show_archive: false,
+            guess_constructor_args: false,
+            compilation_profile: None,
+            language: None,
+            creation_transaction_hash: None,
+        }
+    }
 }
 
 /// Given the broadcast log, it matches transactions with receipts, and tries to verify any
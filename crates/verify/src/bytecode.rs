@@ -1,6 +1,8 @@
 //! The `forge verify-bytecode` command.
 use crate::{
     etherscan::EtherscanVerificationProvider,
+    fluent::SourceArchive,
+    types::VerificationType,
     utils::{
         BytecodeType, JsonResult, check_and_encode_args, check_explorer_args, configure_env_block,
         maybe_predeploy_contract,
@@ -25,8 +27,17 @@
     opts::EtherscanOpts,
     utils::{self, LoadConfig, read_constructor_args_file},
 };
-use foundry_common::{SYSTEM_TRANSACTION_TYPE, is_known_system_sender, shell};
-use foundry_compilers::{artifacts::EvmVersion, info::ContractInfo};
+use foundry_common::{
+    SYSTEM_TRANSACTION_TYPE, is_known_system_sender,
+    provider::RetryProvider,
+    rust_artifact::RustArtifact,
+    rust_contracts::{RustContractsFilter, RustContractsRegistry},
+    shell, wasm,
+};
+use foundry_compilers::{
+    artifacts::{CompactContractBytecode, EvmVersion},
+    info::ContractInfo,
+};
 use foundry_config::{Config, figment, impl_figment_convert};
 use foundry_evm::{
     constants::DEFAULT_CREATE2_DEPLOYER,
@@ -46,6 +57,9 @@ pub struct VerifyBytecodeArgs {
     pub address: Address,
 
     /// The contract identifier in the form `<path>:<contractname>`.
+    ///
+    /// Rust contracts are identified by their package name with the `.wasm` suffix, e.g.
+    /// `PowerCalculator.wasm`.
     pub contract: ContractInfo,
 
     /// The block at which the bytecode should be verified.
@@ -135,6 +149,10 @@ pub async fn run(mut self) -> Result<()> {
         let config = self.load_config()?;
         let provider = utils::get_provider(&config)?;
 
+        if self.is_wasm() {
+            return self.run_wasm(&config, &provider).await;
+        }
+
         // If chain is not set, we try to get it from the RPC.
         // If RPC is not set, the default chain is used.
         let chain = match config.get_rpc_url() {
@@ -581,4 +599,168 @@ pub async fn run(mut self) -> Result<()> {
         }
         Ok(())
     }
+
+    /// Returns true if the contract is a Rust contract, i.e. `--wasm` is set or the contract name
+    /// has the `.wasm` suffix.
+    fn is_wasm(&self) -> bool {
+        self.verifier.wasm || self.contract.name.ends_with(".wasm")
+    }
+
+    /// Verifies a Rust contract.
+    ///
+    /// The crate is rebuilt with the SDK version and build options recorded in its metadata. The
+    /// wasm module is compared with the input of the creation transaction, including the
+    /// constructor arguments, and the rWasm module with the code deployed at the address.
+    async fn run_wasm(&self, config: &Config, provider: &RetryProvider) -> Result<()> {
+        let project = config.project()?;
+        let registry = RustContractsRegistry::with_filter(
+            &project.paths.sources,
+            Some(project.root()),
+            &RustContractsFilter::from_config(config)?,
+        )?;
+        let info = registry.get(&self.contract.name).ok_or_else(|| {
+            eyre::eyre!("Rust contract '{}' not found in project", self.contract.name)
+        })?;
+
+        // `eth_getCode` of Fluent nodes may hide the OwnableAccount wrapper, the raw code is
+        // preferred if the node serves it.
+        let block = self.block.unwrap_or_default();
+        let code = match provider
+            .raw_request::<_, Bytes>("eth_getRawCode".into(), (self.address, block))
+            .await
+        {
+            Ok(code) => code,
+            Err(err) => {
+                trace!(%err, "failed to get raw code, falling back to eth_getCode");
+                provider.get_code_at(self.address).block_id(block).await?
+            }
+        };
+        if code.is_empty() {
+            eyre::bail!("No bytecode found at address {}", self.address);
+        }
+        let onchain_rwasm = crate::utils::deployed_rwasm(&code).ok_or_else(|| {
+            eyre::eyre!("Contract at address {} is not a Rust contract", self.address)
+        })?;
+
+        if !shell::is_json() {
+            sh_println!(
+                "Verifying bytecode for contract {} at address {}",
+                info.package_name,
+                self.address
+            )?;
+        }
+
+        let artifact = RustArtifact::load(info, project.artifacts_path())?;
+        let archive = SourceArchive::collect(&info.path)?.encode()?;
+        let rebuilt = crate::utils::rebuild_rust_contract(
+            project.root(),
+            project.artifacts_path(),
+            info,
+            &artifact,
+            &archive,
+        )?;
+
+        let mut json_results: Vec<JsonResult> = vec![];
+
+        if !self.ignore.is_some_and(|b| b.is_creation()) {
+            let initcode = self.wasm_creation_code(provider).await?;
+
+            // Arguments are passed in a custom section, not appended to the code
+            let provided_constructor_args =
+                if let Some(path) = self.constructor_args_path.to_owned() {
+                    Some(read_constructor_args_file(path)?)
+                } else {
+                    self.constructor_args.to_owned()
+                }
+                .map(|args| {
+                    let contract = CompactContractBytecode {
+                        abi: Some(rebuilt.abi.clone()),
+                        ..Default::default()
+                    };
+                    check_and_encode_args(&contract, args)
+                })
+                .transpose()?
+                .or(self
+                    .encoded_constructor_args
+                    .to_owned()
+                    .map(hex::decode)
+                    .transpose()?);
+            let constructor_args: Option<Bytes> = match provided_constructor_args {
+                Some(args) => Some(args.into()),
+                None => wasm::constructor_args(&initcode)
+                    .wrap_err("Creation code of the contract is not a wasm module")?,
+            };
+            trace!(?constructor_args);
+
+            let local_initcode = match &constructor_args {
+                Some(args) => wasm::with_constructor_args(&rebuilt.bytecode, args),
+                None => rebuilt.bytecode.clone(),
+            };
+            let match_type = crate::utils::match_wasm_bytecodes(&local_initcode, &initcode);
+            crate::utils::print_wasm_result(match_type, BytecodeType::Creation, &mut json_results);
+        }
+
+        if !self.ignore.is_some_and(|b| b.is_runtime()) {
+            let match_type = (*rebuilt.rwasm()? == onchain_rwasm).then_some(VerificationType::Full);
+            crate::utils::print_wasm_result(match_type, BytecodeType::Runtime, &mut json_results);
+        }
+
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string(&json_results)?)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the initcode the Rust contract was deployed with.
+    ///
+    /// The creation transaction is looked up on the explorer given by `--verifier-url`. The
+    /// initcode is its input for direct deployments and is taken from its trace otherwise.
+    async fn wasm_creation_code(&self, provider: &RetryProvider) -> Result<Bytes> {
+        let tx_hash = self
+            .verifier
+            .wasm_client()?
+            .creation_tx_hash(&self.address.to_string())
+            .await?
+            .ok_or_else(|| {
+                eyre::eyre!(
+                    "Creation transaction of contract at address {} not found on the explorer",
+                    self.address
+                )
+            })?;
+        trace!(creation_tx_hash = ?tx_hash);
+
+        let receipt = provider
+            .get_transaction_receipt(tx_hash)
+            .await?
+            .ok_or_else(|| eyre::eyre!("Receipt not found for transaction hash {tx_hash}"))?;
+        if receipt.to.is_none() && receipt.contract_address == Some(self.address)
+            || receipt.to == Some(DEFAULT_CREATE2_DEPLOYER)
+        {
+            let transaction = provider
+                .get_transaction_by_hash(tx_hash)
+                .await?
+                .ok_or_else(|| eyre::eyre!("Transaction not found for hash {tx_hash}"))?;
+            let input = transaction.inner.input();
+            // The CREATE2 deployer takes the salt before the initcode
+            let offset = if receipt.to.is_some() { 32 } else { 0 };
+            return Ok(input.get(offset..).unwrap_or_default().to_vec().into());
+        }
+
+        let traces = provider.trace_transaction(tx_hash).await.unwrap_or_default();
+        traces
+            .iter()
+            .find_map(|trace| match (&trace.trace.result, &trace.trace.action) {
+                (
+                    Some(TraceOutput::Create(CreateOutput { address, .. })),
+                    Action::Create(CreateAction { init, .. }),
+                ) if *address == self.address => Some(init.clone()),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                eyre::eyre!(
+                    "Could not extract the creation code for contract at address {}",
+                    self.address
+                )
+            })
+    }
 }
@@ -1,10 +1,26 @@
+use alloy_primitives::TxHash;
 use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use eyre::{Result, WrapErr, eyre};
-use flate2::{Compression, write::GzEncoder};
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use std::{fs, io::Write, path::Path, time::Duration};
-use tar::Builder;
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use foundry_common::{
+    fs::canonicalize_path,
+    retry::{Retry, RetryError},
+    rust_artifact::RustArtifact,
+};
+use ignore::WalkBuilder;
+use regex::Regex;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::LazyLock,
+    time::Duration,
+};
+use tar::{Archive, Builder};
 
 /// Archive source information
 #[derive(Debug, Clone, Serialize)]
@@ -13,6 +29,19 @@ pub struct ArchiveSourceInfo {
     pub project_path: String,
 }
 
+impl ArchiveSourceInfo {
+    /// Unpacks the archive into `dir` the way the explorer does, returning the path of the
+    /// contract crate.
+    pub fn unpack(&self, dir: &Path) -> Result<PathBuf> {
+        let compressed =
+            BASE64.decode(&self.content).wrap_err("Failed to decode source archive")?;
+        Archive::new(GzDecoder::new(compressed.as_slice()))
+            .unpack(dir)
+            .wrap_err_with(|| format!("Failed to unpack source archive into {}", dir.display()))?;
+        Ok(dir.join(&self.project_path))
+    }
+}
+
 /// Compile settings for the contract
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompileSettings {
@@ -21,6 +50,25 @@ pub struct CompileSettings {
     pub no_default_features: bool,
 }
 
+impl CompileSettings {
+    /// Returns the settings the contract was built with, as recorded in its `metadata.json`.
+    pub fn from_artifact(artifact: &RustArtifact) -> Result<Self> {
+        let build_config = artifact.build_config()?;
+        let sdk_version = artifact.sdk_version()?;
+        if sdk_version.is_empty() {
+            eyre::bail!(
+                "metadata of Rust contract '{}' doesn't record the fluentbase SDK tag",
+                artifact.name
+            );
+        }
+        Ok(Self {
+            sdk_version: sdk_version.to_string(),
+            features: build_config.features,
+            no_default_features: build_config.no_default_features,
+        })
+    }
+}
+
 impl Default for CompileSettings {
     fn default() -> Self {
         Self { sdk_version: "v0.3.6-dev".to_string(), features: vec![], no_default_features: false }
@@ -39,14 +87,14 @@ pub struct VerificationRequest {
 
 impl VerificationRequest {
     /// Create new verification request with archive source
-    pub async fn new_archive(
+    pub fn new_archive(
         contract_name: String,
         address_hash: String,
-        contract_path: &Path,
+        archive: &SourceArchive,
         compile_settings: CompileSettings,
         abi: serde_json::Value,
     ) -> Result<Self> {
-        let archive_source = ArchiveSourceBuilder::create(contract_path).await?;
+        let archive_source = archive.encode()?;
 
         Ok(Self { contract_name, address_hash, archive_source, compile_settings, abi })
     }
@@ -58,6 +106,37 @@ pub struct ApiErrorResponse {
     pub message: String,
 }
 
+/// The verification status of a smart contract, as returned by the Blockscout API
+#[derive(Debug, Default, Deserialize)]
+pub struct SmartContractStatus {
+    #[serde(default)]
+    pub is_verified: Option<bool>,
+}
+
+impl SmartContractStatus {
+    pub fn is_verified(&self) -> bool {
+        self.is_verified.unwrap_or_default()
+    }
+}
+
+/// The address details returned by the Blockscout API, only the creation transaction is read
+#[derive(Debug, Default, Deserialize)]
+pub struct AddressInfo {
+    /// Older Blockscout versions name this `creation_tx_hash`
+    #[serde(default, alias = "creation_tx_hash")]
+    pub creation_transaction_hash: Option<TxHash>,
+}
+
+/// The verification config of a Blockscout instance, only the supported methods are read
+#[derive(Debug, Default, Deserialize)]
+pub struct VerificationConfig {
+    #[serde(default)]
+    pub verification_options: Vec<String>,
+}
+
+/// Name of the Blockscout verification method of WASM contracts
+const FLUENT_VERIFICATION_METHOD: &str = "fluent";
+
 /// Main Fluent verification client
 pub struct FluentVerificationClient {
     base_url: String,
@@ -76,117 +155,326 @@ pub fn new(base_url: String) -> Self {
         Self { base_url: base_url.trim_end_matches('/').to_string(), http_client }
     }
 
-    /// Verify contract using prepared request
-    pub async fn verify(&self, request: VerificationRequest) -> Result<()> {
-        self.send_verification_request(request).await
+    /// Returns the explorer page of the contract at the given address
+    pub fn contract_url(&self, address: &str) -> String {
+        format!("{}/address/{address}?tab=contract", self.base_url.trim_end_matches("/api"))
     }
 
-    /// Send the verification request to Blockscout API
-    async fn send_verification_request(&self, request: VerificationRequest) -> Result<()> {
+    /// Submits the verification request to the Blockscout API.
+    ///
+    /// The submission is retried while the explorer hasn't indexed the contract yet, or fails with
+    /// a transient error. Returns `false` if the contract was already verified.
+    pub async fn verify(&self, request: &VerificationRequest, retry: Retry) -> Result<bool> {
         let url = format!(
-            "{}/v2/smart-contracts/{}/verification/via/fluent",
+            "{}/v2/smart-contracts/{}/verification/via/{FLUENT_VERIFICATION_METHOD}",
             self.base_url, request.address_hash
         );
 
-        // Add delay to allow contract indexing
-        std::thread::sleep(Duration::from_secs(10));
+        trace!(%url, contract = %request.contract_name, "submitting WASM verification request");
+
+        retry
+            .run_async_until_break(|| async {
+                sh_println!(
+                    "\nSubmitting verification for [{}] {}.",
+                    request.contract_name,
+                    request.address_hash
+                )
+                .map_err(RetryError::Break)?;
+
+                let response = self
+                    .http_client
+                    .post(&url)
+                    .json(request)
+                    .send()
+                    .await
+                    .wrap_err("Failed to send HTTP request")
+                    .map_err(RetryError::Retry)?;
+
+                let status = response.status();
+                if status.is_success() {
+                    return Ok(true);
+                }
+
+                let err = Self::api_error(status, response).await;
+                let message = err.to_string().to_lowercase();
+                if message.contains("already verified") {
+                    return Ok(false);
+                }
+                if Self::is_transient(status) || message.contains("not a smart-contract") {
+                    // the contract may not be indexed by the explorer yet
+                    return Err(RetryError::Retry(err.wrap_err("Could not detect deployment")));
+                }
+                Err(RetryError::Break(err))
+            })
+            .await
+            .wrap_err("Failed to submit contract verification")
+    }
+
+    /// Checks whether the contract at the given address is verified.
+    ///
+    /// The check is retried while the verification is pending. Blockscout does not report
+    /// rejected builds, so a contract that is still not verified once all retries are used up is
+    /// treated as a failed verification.
+    pub async fn check(&self, address: &str, retry: Retry) -> Result<()> {
+        retry
+            .run_async_until_break(|| async {
+                let status = self.status(address).await.map_err(RetryError::Retry)?;
+
+                let Some(status) = status else {
+                    return Err(RetryError::Retry(eyre!(
+                        "Contract {address} is not indexed by the explorer yet"
+                    )));
+                };
+
+                if !status.is_verified() {
+                    return Err(RetryError::Retry(eyre!("Verification is still pending...")));
+                }
+
+                let _ = sh_println!("Contract successfully verified");
+                Ok(())
+            })
+            .await
+            .wrap_err("Checking verification result failed")
+    }
+
+    /// Checks that the verifier is reachable and supports the verification of WASM contracts.
+    pub async fn check_support(&self) -> Result<()> {
+        let url = format!("{}/v2/smart-contracts/verification/config", self.base_url);
+        let config: VerificationConfig = self
+            .get(&url, "verification config")
+            .await
+            .wrap_err_with(|| format!("Verifier at {} is not reachable", self.base_url))?
+            .ok_or_else(|| eyre!("Verifier at {} is not a Blockscout API", self.base_url))?;
+
+        if !config.verification_options.iter().any(|method| method == FLUENT_VERIFICATION_METHOD) {
+            eyre::bail!(
+                "Verifier at {} doesn't support WASM contracts, supported methods: {}",
+                self.base_url,
+                config.verification_options.join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the verification status of the contract at the given address, or `None` if the
+    /// explorer doesn't know the contract.
+    pub async fn status(&self, address: &str) -> Result<Option<SmartContractStatus>> {
+        let url = format!("{}/v2/smart-contracts/{address}", self.base_url);
+        self.get(&url, "verification status").await
+    }
+
+    /// Returns the hash of the transaction that created the contract at the given address, or
+    /// `None` if the explorer doesn't know it.
+    pub async fn creation_tx_hash(&self, address: &str) -> Result<Option<TxHash>> {
+        let url = format!("{}/v2/addresses/{address}", self.base_url);
+        let info: Option<AddressInfo> = self.get(&url, "address info").await?;
+        Ok(info.and_then(|info| info.creation_transaction_hash))
+    }
 
+    /// Fetches `what` from the given URL, returning `None` if the explorer responds with 404.
+    async fn get<T: DeserializeOwned>(&self, url: &str, what: &str) -> Result<Option<T>> {
         let response = self
             .http_client
-            .post(&url)
-            .json(&request)
+            .get(url)
             .send()
             .await
-            .wrap_err("Failed to send HTTP request")?;
+            .wrap_err_with(|| format!("Failed to request {what}"))?;
 
         let status = response.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(Self::api_error(status, response).await);
+        }
 
-        if status.is_success() {
-            sh_println!(
-                "Contract submitted for verification. \
-    It will appear at: {} once verified.",
-                format!(
-                    "{}/address/{}?tab=contract",
-                    self.base_url.trim_end_matches("/api"),
-                    request.address_hash
-                )
-            )?;
+        let text = response.text().await.wrap_err_with(|| format!("Failed to read {what}"))?;
+        trace!(%text, "received {what}");
+        Ok(Some(serde_json::from_str(&text).wrap_err_with(|| format!("Failed to parse {what}"))?))
+    }
 
-            Ok(())
-        } else {
-            let error_text = response.text().await.wrap_err("Failed to read error response")?;
+    /// Returns whether the request failed for a reason that may resolve itself on retry
+    fn is_transient(status: StatusCode) -> bool {
+        status == StatusCode::NOT_FOUND
+            || status == StatusCode::TOO_MANY_REQUESTS
+            || status.is_server_error()
+    }
 
-            if let Ok(error_response) = serde_json::from_str::<ApiErrorResponse>(&error_text) {
-                Err(eyre!("API error ({}): {}", status.as_u16(), error_response.message))
-            } else {
-                Err(eyre!("API error ({}): {}", status.as_u16(), error_text))
-            }
+    async fn api_error(status: StatusCode, response: reqwest::Response) -> eyre::Report {
+        let error_text = match response.text().await {
+            Ok(text) => text,
+            Err(err) => return eyre!("API error ({}): {err}", status.as_u16()),
+        };
+
+        if let Ok(error_response) = serde_json::from_str::<ApiErrorResponse>(&error_text) {
+            eyre!("API error ({}): {}", status.as_u16(), error_response.message)
+        } else {
+            eyre!("API error ({}): {}", status.as_u16(), error_text)
         }
     }
 }
 
-/// Archive source helper
-struct ArchiveSourceBuilder;
+/// Files which configure the build of all crates below the directory they're in.
+const BUILD_CONFIG_FILES: &[&str] =
+    &["rust-toolchain", "rust-toolchain.toml", ".cargo/config", ".cargo/config.toml"];
 
-impl ArchiveSourceBuilder {
-    async fn create(contract_path: &Path) -> Result<ArchiveSourceInfo> {
-        let archive_content = Self::create_archive_from_path(contract_path).await?;
-        Ok(ArchiveSourceInfo { content: archive_content, project_path: ".".to_string() })
-    }
+/// The source files of a WASM contract, as uploaded for verification.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceArchive {
+    /// The path of the contract crate, relative to the archive root.
+    pub project_path: String,
+    /// `path relative to the archive root -> content` of all archived files.
+    pub files: BTreeMap<String, Vec<u8>>,
+}
 
-    /// Create a Base64-encoded tar.gz archive from the contract path
-    async fn create_archive_from_path(contract_path: &Path) -> Result<String> {
+impl SourceArchive {
+    /// Collects the sources of the contract at `contract_path`.
+    ///
+    /// For a cargo crate these are the files of the crate and of the local path dependencies it is
+    /// built with, files outside of them which its sources or build scripts reference, the manifest
+    /// and lockfile of its workspace, as well as the toolchain and cargo configs applying to it.
+    /// Files ignored by `.gitignore` are skipped. The archive root is the closest directory
+    /// containing all of them.
+    pub fn collect(contract_path: &Path) -> Result<Self> {
         if contract_path.is_file() {
-            // Single file - create archive with the file and infer project structure
-            let file_name = contract_path
-                .file_name()
-                .ok_or_else(|| eyre!("Invalid file name"))?
-                .to_string_lossy();
-
-            let content =
-                fs::read_to_string(contract_path).wrap_err("Failed to read contract file")?;
-
             // For single files, check if it's in a project directory structure
             if let Some(parent) = contract_path.parent()
                 && parent.join("Cargo.toml").exists()
             {
                 // It's part of a Rust project, archive the whole project
-                return Self::create_tar_gz_archive(parent).await;
+                return Self::collect_crate(parent);
             }
 
-            // Create a minimal project structure for a single file
-            let files = vec![(file_name.to_string(), content)];
-            Self::create_tar_gz_from_files(&files).await
+            let file_name = contract_path
+                .file_name()
+                .ok_or_else(|| eyre!("Invalid file name"))?
+                .to_string_lossy()
+                .into_owned();
+            let content = fs::read(contract_path).wrap_err("Failed to read contract file")?;
+            Ok(Self {
+                project_path: ".".to_string(),
+                files: BTreeMap::from([(file_name, content)]),
+            })
+        } else if contract_path.join("Cargo.toml").is_file() {
+            Self::collect_crate(contract_path)
         } else if contract_path.is_dir() {
-            // Directory - create tar.gz archive of the entire directory
-            Self::create_tar_gz_archive(contract_path).await
+            let mut archive = Self { project_path: ".".to_string(), ..Default::default() };
+            archive.add_dir(contract_path, contract_path)?;
+            if archive.files.is_empty() {
+                return Err(eyre!("No contract files found in directory"));
+            }
+            Ok(archive)
         } else {
             Err(eyre!("Path is neither a file nor a directory"))
         }
     }
 
-    /// Create a tar.gz archive from a directory
-    async fn create_tar_gz_archive(dir_path: &Path) -> Result<String> {
-        let files = Self::collect_contract_files(dir_path)?;
-        Self::create_tar_gz_from_files(&files).await
+    /// Collects the file closure of the crate at `crate_dir` from its cargo metadata.
+    fn collect_crate(crate_dir: &Path) -> Result<Self> {
+        let crate_dir = canonicalize_path(crate_dir)
+            .wrap_err_with(|| format!("Failed to resolve {}", crate_dir.display()))?;
+        let metadata = CargoMetadata::read(&crate_dir)?;
+
+        // packages from a registry or git are fetched by the verifier itself
+        let package_dirs = metadata
+            .dependency_closure(&crate_dir.join("Cargo.toml"))?
+            .into_iter()
+            .filter(|package| package.source.is_none())
+            .filter_map(|package| package.manifest_path.parent().map(Path::to_path_buf))
+            .collect::<Vec<_>>();
+
+        let mut referenced = Vec::new();
+        for dir in &package_dirs {
+            referenced.extend(referenced_paths(dir)?);
+        }
+        referenced.retain(|path| !package_dirs.iter().any(|dir| path.starts_with(dir)));
+
+        let root = package_dirs
+            .iter()
+            .chain(&referenced)
+            .chain([&crate_dir, &metadata.workspace_root])
+            .try_fold(crate_dir.clone(), |root, dir| common_ancestor(&root, dir))
+            .ok_or_else(|| eyre!("Crate sources do not share a common directory"))?;
+
+        let mut archive =
+            Self { project_path: relative_path(&crate_dir, &root)?, files: BTreeMap::new() };
+        for dir in &package_dirs {
+            archive.add_dir(dir, &root)?;
+        }
+        for path in &referenced {
+            if path.is_dir() {
+                archive.add_dir(path, &root)?;
+            } else {
+                archive.add_file(path, &root)?;
+            }
+        }
+        for file in ["Cargo.toml", "Cargo.lock"] {
+            archive.add_file(&metadata.workspace_root.join(file), &root)?;
+        }
+        for dir in package_dirs.iter().chain([&crate_dir, &metadata.workspace_root]) {
+            for ancestor in dir.ancestors().take_while(|ancestor| ancestor.starts_with(&root)) {
+                for file in BUILD_CONFIG_FILES {
+                    archive.add_file(&ancestor.join(file), &root)?;
+                }
+            }
+        }
+
+        Ok(archive)
     }
 
-    /// Create a tar.gz archive from a list of (path, content) tuples
-    async fn create_tar_gz_from_files(files: &[(String, String)]) -> Result<String> {
+    /// Adds all files below `dir` which aren't ignored by `.gitignore`, skipping build outputs,
+    /// hidden files and nested packages, which are added on their own if they are dependencies.
+    fn add_dir(&mut self, dir: &Path, root: &Path) -> Result<()> {
+        let walker = WalkBuilder::new(dir)
+            .hidden(true)
+            .require_git(false)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(|e| {
+                e.depth() == 0
+                    || !e.file_type().is_some_and(|ty| ty.is_dir())
+                    || !(is_build_output(e.path()) || e.path().join("Cargo.toml").is_file())
+            })
+            .build();
+        for entry in walker {
+            let entry =
+                entry.wrap_err_with(|| format!("Failed to read directory: {}", dir.display()))?;
+            if entry.file_type().is_some_and(|ty| ty.is_file()) {
+                self.add_file(entry.path(), root)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the file at `path`, if it exists.
+    fn add_file(&mut self, path: &Path, root: &Path) -> Result<()> {
+        if !path.is_file() {
+            return Ok(());
+        }
+        let relative = relative_path(path, root)?;
+        if !self.files.contains_key(&relative) {
+            let content = fs::read(path)
+                .wrap_err_with(|| format!("Failed to read file: {}", path.display()))?;
+            self.files.insert(relative, content);
+        }
+        Ok(())
+    }
+
+    /// Encodes the archive as a Base64-encoded tar.gz.
+    pub fn encode(&self) -> Result<ArchiveSourceInfo> {
         let mut tar_data = Vec::new();
 
         // Create tar archive
         {
             let mut tar = Builder::new(&mut tar_data);
 
-            for (path, content) in files {
+            for (path, content) in &self.files {
                 let mut header = tar::Header::new_gnu();
                 header.set_path(path).wrap_err_with(|| format!("Failed to set path for {path}"))?;
                 header.set_size(content.len() as u64);
                 header.set_mode(0o644);
                 header.set_cksum();
 
-                tar.append(&header, content.as_bytes())
+                tar.append(&header, content.as_slice())
                     .wrap_err_with(|| format!("Failed to append file {path}"))?;
             }
 
@@ -199,63 +487,154 @@ async fn create_tar_gz_from_files(files: &[(String, String)]) -> Result<String>
         let compressed_data = encoder.finish().wrap_err("Failed to finish gzip compression")?;
 
         // Encode to base64
-        Ok(BASE64.encode(&compressed_data))
+        Ok(ArchiveSourceInfo {
+            content: BASE64.encode(&compressed_data),
+            project_path: self.project_path.clone(),
+        })
     }
+}
 
-    /// Collect all contract files from a directory (only .rs, .toml, .lock)
-    fn collect_contract_files(dir: &Path) -> Result<Vec<(String, String)>> {
-        let mut files = Vec::new();
+/// The parts of `cargo metadata` output needed to find the sources of a crate.
+#[derive(Debug, Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+    resolve: CargoResolve,
+    workspace_root: PathBuf,
+}
 
-        fn visit_dir(
-            dir: &Path,
-            base_path: &Path,
-            files: &mut Vec<(String, String)>,
-        ) -> Result<()> {
-            for entry in fs::read_dir(dir)
-                .wrap_err_with(|| format!("Failed to read directory: {}", dir.display()))?
-            {
-                let entry = entry.wrap_err("Failed to read directory entry")?;
-                let path = entry.path();
-
-                if path.is_file() {
-                    // Include only specific file types for WASM contracts
-                    if let Some(ext) = path.extension() {
-                        let ext_str = ext.to_string_lossy();
-                        if matches!(ext_str.as_ref(), "rs" | "toml" | "lock") {
-                            let content = fs::read_to_string(&path).wrap_err_with(|| {
-                                format!("Failed to read file: {}", path.display())
-                            })?;
-                            let relative_path = path
-                                .strip_prefix(base_path)
-                                .wrap_err("Failed to create relative path")?
-                                .to_string_lossy()
-                                .to_string();
-                            files.push((relative_path, content));
-                        }
-                    }
-                } else if path.is_dir() {
-                    // Skip directories that shouldn't be included
-                    if let Some(dir_name) = path.file_name() {
-                        let dir_str = dir_name.to_string_lossy();
-                        if !matches!(dir_str.as_ref(), "target" | ".git" | "node_modules")
-                            && !dir_str.starts_with('.')
-                        {
-                            visit_dir(&path, base_path, files)?;
-                        }
-                    }
+#[derive(Debug, Deserialize)]
+struct CargoPackage {
+    id: String,
+    manifest_path: PathBuf,
+    source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoResolve {
+    nodes: Vec<CargoNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoNode {
+    id: String,
+    deps: Vec<CargoNodeDep>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoNodeDep {
+    pkg: String,
+    dep_kinds: Vec<CargoDepKind>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoDepKind {
+    kind: Option<String>,
+}
+
+impl CargoMetadata {
+    /// Runs `cargo metadata` for the crate at `crate_dir`, without updating its lockfile.
+    fn read(crate_dir: &Path) -> Result<Self> {
+        let output = Command::new("cargo")
+            .current_dir(crate_dir)
+            .args(["metadata", "--format-version", "1", "--locked", "--manifest-path"])
+            .arg(crate_dir.join("Cargo.toml"))
+            .stdin(Stdio::null())
+            .output()
+            .wrap_err("Failed to run `cargo metadata`")?;
+        if !output.status.success() {
+            return Err(eyre!(
+                "`cargo metadata` failed for {}, make sure its Cargo.lock is up to date:\n{}",
+                crate_dir.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        serde_json::from_slice(&output.stdout).wrap_err("Failed to parse `cargo metadata` output")
+    }
+
+    /// Returns the package with the manifest at `manifest_path` and all packages it depends on to
+    /// build, following the resolved dependency graph. Dev-dependencies are skipped.
+    fn dependency_closure(&self, manifest_path: &Path) -> Result<Vec<&CargoPackage>> {
+        let root = self
+            .packages
+            .iter()
+            .find(|package| package.manifest_path == manifest_path)
+            .ok_or_else(|| eyre!("No package found for {}", manifest_path.display()))?;
+        let nodes = self
+            .resolve
+            .nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut closure = BTreeSet::from([root.id.as_str()]);
+        let mut queue = vec![root.id.as_str()];
+        while let Some(id) = queue.pop() {
+            let Some(node) = nodes.get(id) else { continue };
+            for dep in &node.deps {
+                let is_dev_only = !dep.dep_kinds.is_empty()
+                    && dep.dep_kinds.iter().all(|kind| kind.kind.as_deref() == Some("dev"));
+                if !is_dev_only && closure.insert(dep.pkg.as_str()) {
+                    queue.push(dep.pkg.as_str());
                 }
             }
-            Ok(())
         }
 
-        visit_dir(dir, dir, &mut files)?;
+        Ok(self.packages.iter().filter(|package| closure.contains(package.id.as_str())).collect())
+    }
+}
 
-        if files.is_empty() {
-            return Err(eyre!("No contract files (.rs, .toml, .lock) found in directory"));
+/// Returns the existing paths which the sources or build script of the package at `package_dir`
+/// read, as far as they are given literally: `include_bytes!`/`include_str!` paths, resolved
+/// relative to the including file, and `cargo:rerun-if-changed` paths, resolved relative to the
+/// package.
+fn referenced_paths(package_dir: &Path) -> Result<Vec<PathBuf>> {
+    static INCLUDE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"include_(?:bytes|str)![ \t\r\n]*\([ \t\r\n]*"([^"]+)""#).unwrap()
+    });
+    static RERUN_IF_CHANGED: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"cargo:+rerun-if-changed=([^"]+)""#).unwrap());
+
+    let mut paths = Vec::new();
+    let walker = WalkBuilder::new(package_dir)
+        .hidden(true)
+        .require_git(false)
+        .filter_entry(|e| !is_build_output(e.path()))
+        .build();
+    for entry in walker.filter_map(Result::ok) {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        let Ok(source) = fs::read_to_string(path) else { continue };
+        let Some(dir) = path.parent() else { continue };
+        for captures in INCLUDE.captures_iter(&source) {
+            paths.push(dir.join(&captures[1]));
         }
+        for captures in RERUN_IF_CHANGED.captures_iter(&source) {
+            paths.push(package_dir.join(&captures[1]));
+        }
+    }
+
+    Ok(paths.into_iter().filter_map(|path| canonicalize_path(path).ok()).collect())
+}
 
-        Ok(files)
+/// Returns whether `dir` holds build outputs which are never archived.
+fn is_build_output(dir: &Path) -> bool {
+    dir.file_name().is_some_and(|name| name == "target" || name == "node_modules")
+}
+
+/// Returns the closest directory containing both paths.
+fn common_ancestor(a: &Path, b: &Path) -> Option<PathBuf> {
+    a.ancestors().find(|ancestor| b.starts_with(ancestor)).map(Path::to_path_buf)
+}
+
+/// Returns `path` relative to `root` with `/` separators, as stored in the archive.
+fn relative_path(path: &Path, root: &Path) -> Result<String> {
+    let relative = path.strip_prefix(root).wrap_err("Failed to create relative path")?;
+    if relative.as_os_str().is_empty() {
+        return Ok(".".to_string());
     }
+    Ok(relative.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/"))
 }
 
 #[cfg(test)]
@@ -264,26 +643,178 @@ mod tests {
     use serde_json::json;
     use tempfile::TempDir;
 
-    #[tokio::test]
-    async fn test_archive_source_creation() {
+    #[test]
+    fn test_archive_source_creation() {
         let temp_dir = TempDir::new().unwrap();
         let file_path = temp_dir.path().join("lib.rs");
         fs::write(&file_path, "fn main() {}").unwrap();
 
-        let result = ArchiveSourceBuilder::create(&file_path).await;
-        assert!(result.is_ok());
+        let archive = SourceArchive::collect(&file_path).unwrap();
+        assert_eq!(archive.files.keys().collect::<Vec<_>>(), ["lib.rs"]);
 
-        let archive_source = result.unwrap();
+        let archive_source = archive.encode().unwrap();
         assert!(!archive_source.content.is_empty());
         assert_eq!(archive_source.project_path, ".");
     }
 
+    #[test]
+    fn test_archive_workspace_crate() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let write = |path: &str, content: &[u8]| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        let package = |name: &str, deps: &str| {
+            format!(
+                "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n{deps}"
+            )
+        };
+
+        write("Cargo.toml", b"[workspace]\nmembers = [\"contracts/*\"]\nresolver = \"2\"\n");
+        write(
+            "Cargo.lock",
+            b"version = 4\n\n\
+              [[package]]\nname = \"counter\"\nversion = \"0.1.0\"\n\
+              dependencies = [\n \"helpers\",\n \"shared\",\n]\n\n\
+              [[package]]\nname = \"helpers\"\nversion = \"0.1.0\"\n\n\
+              [[package]]\nname = \"other\"\nversion = \"0.1.0\"\n\n\
+              [[package]]\nname = \"shared\"\nversion = \"0.1.0\"\n",
+        );
+        write("rust-toolchain.toml", b"[toolchain]\nchannel = \"1.88\"\n");
+        write(".cargo/config.toml", b"[build]\n");
+        write(".gitignore", b"secrets.txt\n");
+        write(
+            "contracts/counter/Cargo.toml",
+            package(
+                "counter",
+                "[dependencies]\nshared = { path = \"../../shared\" }\n\n\
+                 [dev-dependencies]\nhelpers = { path = \"../../helpers\" }\n",
+            )
+            .as_bytes(),
+        );
+        write(
+            "contracts/counter/src/lib.rs",
+            b"pub use shared::VALUE;\n\
+              pub const DATA: &[u8] = include_bytes!(\"../../../assets/data.bin\");\n",
+        );
+        write("contracts/counter/fixture.bin", &[0xff, 0x00, 0xfe]);
+        write("contracts/counter/secrets.txt", b"ignored");
+        write("contracts/counter/target/debug/out", b"ignored");
+        write("contracts/counter/.git/HEAD", b"ignored");
+        write("contracts/other/Cargo.toml", package("other", "").as_bytes());
+        write("contracts/other/src/lib.rs", b"");
+        write("shared/Cargo.toml", package("shared", "").as_bytes());
+        write("shared/src/lib.rs", b"pub const VALUE: u8 = 1;");
+        write("helpers/Cargo.toml", package("helpers", "").as_bytes());
+        write("helpers/src/lib.rs", b"");
+        write("assets/data.bin", &[0x01]);
+        write("assets/unused.bin", &[0x02]);
+        write("unrelated/notes.txt", b"ignored");
+
+        let archive = SourceArchive::collect(&root.join("contracts/counter")).unwrap();
+        assert_eq!(archive.project_path, "contracts/counter");
+        assert_eq!(
+            archive.files.keys().map(String::as_str).collect::<Vec<_>>(),
+            [
+                ".cargo/config.toml",
+                "Cargo.lock",
+                "Cargo.toml",
+                "assets/data.bin",
+                "contracts/counter/Cargo.toml",
+                "contracts/counter/fixture.bin",
+                "contracts/counter/src/lib.rs",
+                "rust-toolchain.toml",
+                "shared/Cargo.toml",
+                "shared/src/lib.rs",
+            ]
+        );
+        assert_eq!(archive.files["contracts/counter/fixture.bin"], [0xff, 0x00, 0xfe]);
+
+        let unpacked = TempDir::new().unwrap();
+        let crate_dir = archive.encode().unwrap().unpack(unpacked.path()).unwrap();
+        assert_eq!(crate_dir, unpacked.path().join("contracts/counter"));
+        assert_eq!(fs::read(crate_dir.join("fixture.bin")).unwrap(), [0xff, 0x00, 0xfe]);
+        assert_eq!(fs::read(unpacked.path().join("assets/data.bin")).unwrap(), [0x01]);
+    }
+
+    #[test]
+    fn test_archive_requires_lockfile() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"counter\"\nversion = \"0.1.0\"\n")
+            .unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+
+        let err = SourceArchive::collect(root).unwrap_err();
+        assert!(err.to_string().contains("Cargo.lock"), "{err}");
+        assert!(!root.join("Cargo.lock").exists());
+    }
+
     #[test]
     fn test_client_creation() {
         let client = FluentVerificationClient::new("https://example.com/".to_string());
         assert_eq!(client.base_url, "https://example.com");
     }
 
+    #[test]
+    fn test_transient_errors() {
+        assert!(FluentVerificationClient::is_transient(StatusCode::NOT_FOUND));
+        assert!(FluentVerificationClient::is_transient(StatusCode::TOO_MANY_REQUESTS));
+        assert!(FluentVerificationClient::is_transient(StatusCode::BAD_GATEWAY));
+        assert!(!FluentVerificationClient::is_transient(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn test_contract_status() {
+        let client = FluentVerificationClient::new("https://example.com/api".to_string());
+        assert_eq!(
+            client.contract_url("0x1234"),
+            "https://example.com/address/0x1234?tab=contract"
+        );
+
+        let status: SmartContractStatus =
+            serde_json::from_value(json!({ "is_verified": true, "name": "Counter" })).unwrap();
+        assert!(status.is_verified());
+
+        let status: SmartContractStatus =
+            serde_json::from_value(json!({ "is_verified": null })).unwrap();
+        assert!(!status.is_verified());
+    }
+
+    #[test]
+    fn test_verification_config() {
+        let config: VerificationConfig = serde_json::from_value(json!({
+            "solidity_compiler_versions": ["v0.8.30+commit.73712a01"],
+            "verification_options": ["flattened-code", "standard-input", "fluent"]
+        }))
+        .unwrap();
+        assert!(config.verification_options.iter().any(|m| m == FLUENT_VERIFICATION_METHOD));
+
+        let config: VerificationConfig = serde_json::from_value(json!({})).unwrap();
+        assert!(config.verification_options.is_empty());
+    }
+
+    #[test]
+    fn test_address_info() {
+        let hash = "0x2f5d3a8b5a8c3f0c0c0dbe9e30c6f8b5dbeaf3c4e5e5e9d7e7c1d0a3b2c1d0e9";
+        let info: AddressInfo = serde_json::from_value(
+            json!({ "creation_transaction_hash": hash, "is_contract": true }),
+        )
+        .unwrap();
+        assert_eq!(info.creation_transaction_hash, Some(hash.parse().unwrap()));
+
+        let info: AddressInfo =
+            serde_json::from_value(json!({ "creation_tx_hash": hash })).unwrap();
+        assert_eq!(info.creation_transaction_hash, Some(hash.parse().unwrap()));
+
+        let info: AddressInfo =
+            serde_json::from_value(json!({ "creation_transaction_hash": null })).unwrap();
+        assert_eq!(info.creation_transaction_hash, None);
+    }
+
     #[test]
     fn test_serialization() {
         let request = VerificationRequest {
@@ -1,4 +1,4 @@
-use crate::{bytecode::VerifyBytecodeArgs, types::VerificationType};
+use crate::{bytecode::VerifyBytecodeArgs, fluent::ArchiveSourceInfo, types::VerificationType};
 use alloy_dyn_abi::DynSolValue;
 use alloy_primitives::{Address, Bytes, TxKind, U256};
 use alloy_provider::{
@@ -15,7 +15,8 @@
 };
 use foundry_common::{
     abi::encode_args, compile::ProjectCompiler, ignore_metadata_hash, provider::RetryProvider,
-    shell,
+    rust_artifact::RustArtifact, rust_compiler::RustWasmCompiler, rust_contracts::RustProjectInfo,
+    shell, wasm,
 };
 use foundry_compilers::artifacts::{BytecodeHash, CompactContractBytecode, EvmVersion};
 use foundry_config::Config;
@@ -33,6 +34,7 @@
 use revm::{bytecode::Bytecode, database::Database, primitives::hardfork::SpecId};
 use semver::{BuildMetadata, Version};
 use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
 use yansi::Paint;
 
 /// Enum to represent the type of bytecode being verified
@@ -140,6 +142,96 @@ pub fn print_result(
     }
 }
 
+/// Compares the initcode of a rebuilt Rust contract with the one it was deployed with.
+///
+/// The match is partial if the modules only differ in their metadata sections, which don't affect
+/// execution, see [`wasm::without_metadata_sections`]. The constructor arguments must be the same.
+pub fn match_wasm_bytecodes(local_initcode: &[u8], initcode: &[u8]) -> Option<VerificationType> {
+    if local_initcode == initcode {
+        return Some(VerificationType::Full);
+    }
+    match (
+        wasm::without_metadata_sections(local_initcode),
+        wasm::without_metadata_sections(initcode),
+    ) {
+        (Ok(local), Ok(onchain)) if local == onchain => Some(VerificationType::Partial),
+        _ => None,
+    }
+}
+
+/// Returns the rWasm module of a deployed Rust contract, or `None` if `code` isn't one.
+///
+/// The module is either stored as is or as the metadata of an OwnableAccount.
+pub fn deployed_rwasm(code: &Bytes) -> Option<Bytes> {
+    if wasm::is_rwasm(code) {
+        return Some(code.clone());
+    }
+    match Bytecode::new_raw_checked(code.clone()).ok()? {
+        Bytecode::OwnableAccount(account) if wasm::is_rwasm(&account.metadata) => {
+            Some(account.metadata)
+        }
+        _ => None,
+    }
+}
+
+/// Directory inside the artifacts directory Rust contracts are rebuilt in for verification.
+const RUST_REBUILD_DIR: &str = ".rust-verify";
+
+/// Rebuilds a Rust contract the way the explorer verifies it.
+///
+/// The source archive of the crate is unpacked below the artifacts directory and built there in
+/// the build image of the SDK version recorded in the metadata of its local `artifact`, with the
+/// recorded build options. The rWasm module is always generated.
+pub fn rebuild_rust_contract(
+    root: &Path,
+    artifacts_root: &Path,
+    info: &RustProjectInfo,
+    artifact: &RustArtifact,
+    archive: &ArchiveSourceInfo,
+) -> Result<RustArtifact> {
+    let mut options = artifact.build_config()?;
+    options.docker = true;
+    options.docker_tag = Some(artifact.sdk_version()?.to_string());
+    if !options.artifacts.iter().any(|name| name == "rwasm") {
+        options.artifacts.push("rwasm".to_string());
+    }
+
+    // Must be below the project root, which is mounted into the build container
+    let build_root = artifacts_root.join(RUST_REBUILD_DIR);
+    let _ = fs::remove_dir_all(&build_root);
+    let rebuilt = archive.unpack(&build_root.join("sources")).and_then(|path| {
+        let info = RustProjectInfo { path, ..info.clone() };
+        RustWasmCompiler::new(root, &build_root)
+            .with_quiet(shell::is_json())
+            .build_crate(&info, &options)
+    });
+    let _ = fs::remove_dir_all(&build_root);
+    rebuilt
+}
+
+/// Prints the result of verifying a Rust contract, see [`print_result`].
+pub fn print_wasm_result(
+    res: Option<VerificationType>,
+    bytecode_type: BytecodeType,
+    json_results: &mut Vec<JsonResult>,
+) {
+    if shell::is_json() {
+        let message = res.is_none().then(|| format!("{bytecode_type:?} code did not match"));
+        json_results.push(JsonResult { bytecode_type, match_type: res, message });
+    } else if let Some(res) = res {
+        let _ = sh_println!(
+            "{} with status {}",
+            format!("{bytecode_type:?} code matched").green().bold(),
+            res.green().bold()
+        );
+    } else {
+        let _ = sh_err!(
+            "{bytecode_type:?} code did not match - this may be due to a different fluentbase SDK \
+             version or build options"
+        );
+    }
+}
+
 fn is_partial_match(
     mut local_bytecode: &[u8],
     mut bytecode: &[u8],
@@ -419,6 +511,8 @@ pub async fn ensure_solc_build_metadata(version: Version) -> Result<Version> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::bytes;
+    use revm::bytecode::ownable_account::OwnableAccountBytecode;
 
     #[test]
     fn test_host_only() {
@@ -426,4 +520,50 @@ fn test_host_only() {
         assert!(is_host_only(&Url::parse("https://blockscout.net/").unwrap()));
         assert!(is_host_only(&Url::parse("https://blockscout.net").unwrap()));
     }
+
+    // (module (func))
+    const MODULE: [u8; 24] = [
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type
+        0x03, 0x02, 0x01, 0x00, // function
+        0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // code
+    ];
+
+    #[test]
+    fn matches_wasm_bytecodes() {
+        let initcode = wasm::with_constructor_args(&MODULE, &[0x2a; 32]);
+        assert_eq!(match_wasm_bytecodes(&initcode, &initcode), Some(VerificationType::Full));
+        assert_eq!(match_wasm_bytecodes(&MODULE, &MODULE), Some(VerificationType::Full));
+
+        let mut named = MODULE.to_vec();
+        named.extend([0x00, 0x05, 0x04, b'n', b'a', b'm', b'e']);
+        let named_initcode = wasm::with_constructor_args(&named, &[0x2a; 32]);
+        assert_eq!(
+            match_wasm_bytecodes(&named_initcode, &initcode),
+            Some(VerificationType::Partial)
+        );
+
+        let other_args = wasm::with_constructor_args(&MODULE, &[0x01; 32]);
+        assert_eq!(match_wasm_bytecodes(&other_args, &initcode), None);
+        assert_eq!(match_wasm_bytecodes(&MODULE, &initcode), None);
+
+        let mut other_code = MODULE;
+        other_code[21] = 0x01;
+        assert_eq!(match_wasm_bytecodes(&other_code, &MODULE), None);
+        assert_eq!(match_wasm_bytecodes(&[0xef, 0x52], &MODULE), None);
+    }
+
+    #[test]
+    fn unwraps_deployed_rwasm() {
+        let rwasm = bytes!("ef5200010203");
+        assert_eq!(deployed_rwasm(&rwasm), Some(rwasm.clone()));
+
+        let owner = Address::with_last_byte(0x52);
+        let account = Bytecode::OwnableAccount(OwnableAccountBytecode::new(owner, rwasm.clone()));
+        assert_eq!(deployed_rwasm(&account.original_bytes()), Some(rwasm));
+
+        let account = Bytecode::OwnableAccount(OwnableAccountBytecode::new(owner, bytes!("00")));
+        assert_eq!(deployed_rwasm(&account.original_bytes()), None);
+        assert_eq!(deployed_rwasm(&bytes!("6080604052")), None);
+    }
 }
@@ -3,28 +3,35 @@
 use crate::{
     RetryArgs,
     etherscan::EtherscanVerificationProvider,
+    fluent::{CompileSettings, FluentVerificationClient, SourceArchive},
     provider::{VerificationContext, VerificationProvider, VerificationProviderType},
+    retry::RETRY_CHECK_ON_VERIFY,
     utils::is_host_only,
 };
 use alloy_primitives::{Address, TxHash, map::HashSet};
 use alloy_provider::Provider;
 use clap::{Parser, ValueEnum, ValueHint};
-use eyre::Result;
+use eyre::{Result, WrapErr};
 use foundry_cli::{
     opts::{EtherscanOpts, RpcOpts},
     utils::{self, LoadConfig},
 };
 use foundry_common::{
-    ContractsByArtifact, compile::ProjectCompiler, rust_contracts::RustContractsRegistry,
+    ContractsByArtifact,
+    compile::ProjectCompiler,
+    rust_artifact::RustArtifact,
+    rust_contracts::{RustContractsFilter, RustContractsRegistry, RustProjectInfo},
+};
+use foundry_compilers::{
+    Project, artifacts::EvmVersion, compilers::solc::Solc, info::ContractInfo,
 };
-use foundry_compilers::{artifacts::EvmVersion, compilers::solc::Solc, info::ContractInfo};
 use foundry_config::{
     Chain, Config, SolcReq, figment, impl_figment_convert, impl_figment_convert_cast,
 };
 use itertools::Itertools;
 use reqwest::Url;
 use semver::BuildMetadata;
-use std::{fs, path::PathBuf};
+use std::path::PathBuf;
 
 /// The programming language used for smart contract development.
 ///
@@ -81,6 +88,22 @@ pub struct VerifierArgs {
     pub wasm: bool,
 }
 
+impl VerifierArgs {
+    /// Returns the client verifying WASM contracts against the configured verifier URL.
+    pub(crate) fn wasm_client(&self) -> Result<FluentVerificationClient> {
+        let Some(verifier_url) = &self.verifier_url else {
+            eyre::bail!("WASM verification requires --verifier-url to be set");
+        };
+        match Url::parse(verifier_url) {
+            Ok(url) if is_host_only(&url) => eyre::bail!(
+                "Provided URL `{verifier_url}` is host only.\n Did you mean to use the API endpoint `{verifier_url}/api`?"
+            ),
+            Ok(_) => Ok(FluentVerificationClient::new(verifier_url.clone())),
+            Err(url_err) => eyre::bail!("Invalid URL {verifier_url} provided: {url_err}"),
+        }
+    }
+}
+
 impl Default for VerifierArgs {
     fn default() -> Self {
         Self {
@@ -168,6 +191,13 @@ pub struct VerifyArgs {
     #[arg(long, conflicts_with = "flatten")]
     pub show_standard_json_input: bool,
 
+    /// Prints the files of the source archive of a WASM contract instead of submitting it.
+    ///
+    /// The archive holds the contract crate, its path dependencies, the workspace manifest and
+    /// lockfile, and the toolchain and cargo configs applying to the crate.
+    #[arg(long, requires = "wasm")]
+    pub show_archive: bool,
+
     /// Use the Yul intermediate representation compilation pipeline.
     #[arg(long)]
     pub via_ir: bool,
@@ -342,101 +372,120 @@ pub async fn run(mut self) -> Result<()> {
         })
     }
 
-    async fn handle_wasm_verification(&self) -> Result<()> {
-        // Get verifier URL (required for WASM)
-        // Validate URL following the same pattern as the original code
-        let base_url = if let Some(verifier_url) = &self.verifier.verifier_url {
-            match Url::parse(verifier_url) {
-                Ok(url) => {
-                    if is_host_only(&url) {
-                        eyre::bail!(
-                            "Provided URL `{verifier_url}` is host only.\n Did you mean to use the API endpoint `{verifier_url}/api`?"
-                        );
-                    }
-                    verifier_url.clone()
-                }
-                Err(url_err) => {
-                    eyre::bail!("Invalid URL {verifier_url} provided: {url_err}");
-                }
-            }
-        } else {
-            eyre::bail!("WASM verification requires --verifier-url to be set");
-        };
-
-        // resolve context for wasm
-
+    /// Returns the Rust contract to verify and the project it belongs to.
+    fn rust_contract(&self) -> Result<(Project, RustProjectInfo)> {
         let mut config = self.load_config()?;
         config.libraries.extend(self.libraries.clone());
 
-        // Find Project & Compile
         let project = config.project()?;
-        let rust_registry =
-            RustContractsRegistry::new(&project.paths.sources, Some(project.root()))?;
+        let rust_registry = RustContractsRegistry::with_filter(
+            &project.paths.sources,
+            Some(project.root()),
+            &RustContractsFilter::from_config(&config)?,
+        )?;
 
         let contract_info = self
             .contract
             .as_ref()
             .ok_or_else(|| eyre::eyre!("Contract name is required for WASM verification"))?;
 
-        let pkg_info = rust_registry.get(&contract_info.name).ok_or_else(|| {
+        let pkg_info = rust_registry.get(&contract_info.name).cloned().ok_or_else(|| {
             eyre::eyre!("Rust contract '{}' not found in project", contract_info.name)
         })?;
+        Ok((project, pkg_info))
+    }
 
-        let artifact_dir = project.artifacts_path().join(contract_info.clone().name);
-        let abi: serde_json::Value =
-            serde_json::from_str(&fs::read_to_string(artifact_dir.join("abi.json"))?)?;
-        let metadata: serde_json::Value =
-            serde_json::from_str(&fs::read_to_string(artifact_dir.join("metadata.json"))?)?;
-        // Extract compile settings from metadata
-        let compile_settings = crate::fluent::CompileSettings {
-            sdk_version: metadata["environment"]["fluentbase_sdk"]["git_tag"]
-                .as_str()
-                .unwrap_or("unknown")
-                .to_string(),
-            features: metadata["build_config"]["features"]
-                .as_array()
-                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
-                .unwrap_or_default(),
-            no_default_features: metadata["build_config"]["no_default_features"]
-                .as_bool()
-                .unwrap_or(true),
-        };
+    /// Checks that a WASM contract can be verified once it's deployed.
+    ///
+    /// The verifier must support WASM contracts, the contract must have been built with the
+    /// `metadata` artifact, and building its source archive with the recorded settings must
+    /// reproduce the local artifact.
+    pub async fn wasm_preflight_check(&self) -> Result<()> {
+        let (project, pkg_info) = self.rust_contract()?;
+
+        self.verifier.wasm_client()?.check_support().await?;
+
+        let artifact = RustArtifact::load(&pkg_info, project.artifacts_path())?;
+        CompileSettings::from_artifact(&artifact)?;
+        let archive = SourceArchive::collect(&pkg_info.path)?.encode()?;
+
+        let rebuilt = crate::utils::rebuild_rust_contract(
+            project.root(),
+            project.artifacts_path(),
+            &pkg_info,
+            &artifact,
+            &archive,
+        )
+        .wrap_err_with(|| {
+            format!("Rust contract '{}' can't be built for verification", pkg_info.package_name)
+        })?;
+        // The local artifact may have been built without the rWasm module
+        let rwasm_differs = artifact.rwasm.is_some() && rebuilt.rwasm != artifact.rwasm;
+        if rebuilt.bytecode != artifact.bytecode || rwasm_differs {
+            eyre::bail!(
+                "Rust contract '{}' is not reproducible: building it with the recorded settings \
+                 produced different bytecode than the local artifact at {}, rebuild it with Docker \
+                 before deploying",
+                pkg_info.package_name,
+                artifact.dir.display()
+            );
+        }
+        Ok(())
+    }
+
+    async fn handle_wasm_verification(&self) -> Result<()> {
+        let (project, pkg_info) = self.rust_contract()?;
+
+        let archive = SourceArchive::collect(&pkg_info.path)?;
+        if self.show_archive {
+            sh_println!("Project path: {}", archive.project_path)?;
+            for (path, content) in &archive.files {
+                sh_println!("{path} ({} bytes)", content.len())?;
+            }
+            return Ok(());
+        }
+
+        let client = self.verifier.wasm_client()?;
+        let address = self.address.to_string();
+
+        if !self.skip_is_verified_check
+            && let Some(status) = client.status(&address).await?
+            && status.is_verified()
+        {
+            sh_println!(
+                "\nContract [{}] {:?} is already verified. Skipping verification.",
+                pkg_info.package_name,
+                self.address.to_checksum(None)
+            )?;
+            return Ok(());
+        }
+
+        let artifact = RustArtifact::load(&pkg_info, project.artifacts_path())?;
+        let compile_settings = CompileSettings::from_artifact(&artifact)?;
+        let abi = serde_json::to_value(&artifact.abi)?;
 
-        // Create verification request using new API
         let request = crate::fluent::VerificationRequest::new_archive(
             pkg_info.package_name.clone(),
-            self.address.to_string(),
-            &pkg_info.path,
+            address,
+            &archive,
             compile_settings,
             abi,
-        ).await.map_err(|err| {
-            // Enhanced error handling for archive creation
-            if let Some(verifier_url) = &self.verifier.verifier_url
-                && let Ok(url) = Url::parse(verifier_url)
-                    && is_host_only(&url) {
-                        return err.wrap_err(format!(
-                            "Archive creation failed. Also check URL `{verifier_url}` - it appears to be host only.\n\
-                         For WASM verification, try: `{verifier_url}/api/v1/fluent/verify-wasm`"
-                        ));
-                    }
-            err
-        })?;
+        )?;
 
-        // Create client and send verification
-        let client = crate::fluent::FluentVerificationClient::new(base_url);
-        client.verify(request).await.map_err(|err| {
-            // Enhanced error handling for verification
-            if let Some(verifier_url) = &self.verifier.verifier_url
-                && let Ok(url) = Url::parse(verifier_url)
-                && is_host_only(&url)
-            {
-                return err.wrap_err(format!(
-                    "Verification failed. URL `{verifier_url}` is host only.\n\
-                         For WASM verification, try: `{verifier_url}/api/v1/fluent/verify-wasm`"
-                ));
-            }
-            err
-        })
+        if !client.verify(&request, self.retry.into_retry()).await? {
+            sh_println!("Contract source code already verified")?;
+            return Ok(());
+        }
+
+        sh_println!(
+            "Submitted contract for verification:\n\tURL: {}",
+            client.contract_url(&request.address_hash)
+        )?;
+
+        if self.watch {
+            return client.check(&request.address_hash, RETRY_CHECK_ON_VERIFY.into_retry()).await;
+        }
+        Ok(())
     }
 
     /// Returns the configured verification provider
@@ -627,6 +676,8 @@ pub struct VerifyCheckArgs {
     /// For Etherscan - Submission GUID.
     ///
     /// For Sourcify - Verification Job ID.
+    ///
+    /// For WASM contracts - The address of the contract.
     pub id: String,
 
     #[command(flatten)]
@@ -648,6 +699,9 @@ pub async fn run(self) -> Result<()> {
             "Checking verification status on {}",
             self.etherscan.chain.unwrap_or_default()
         )?;
+        if self.verifier.wasm {
+            return self.verifier.wasm_client()?.check(&self.id, self.retry.into_retry()).await;
+        }
         self.verifier
             .verifier
             .client(
@@ -717,4 +771,47 @@ fn can_parse_new_compiler_flags() {
         assert!(args.no_auto_detect);
         assert_eq!(args.use_solc.as_deref(), Some("0.8.23"));
     }
+
+    #[test]
+    fn can_parse_show_archive() {
+        let args = VerifyArgs::parse_from([
+            "foundry-cli",
+            "0x0000000000000000000000000000000000000000",
+            "Counter",
+            "--wasm",
+            "--show-archive",
+        ]);
+        assert!(args.show_archive);
+
+        let args = VerifyArgs::try_parse_from([
+            "foundry-cli",
+            "0x0000000000000000000000000000000000000000",
+            "Counter",
+            "--show-archive",
+        ]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn can_parse_verify_check_wasm() {
+        let args = VerifyCheckArgs::parse_from([
+            "foundry-cli",
+            "0x0000000000000000000000000000000000000000",
+            "--wasm",
+            "--verifier-url",
+            "https://example.com",
+        ]);
+        assert!(args.verifier.wasm);
+        let err = args.verifier.wasm_client().err().unwrap().to_string();
+        assert!(err.contains("is host only"), "{err}");
+
+        let args = VerifyCheckArgs::parse_from([
+            "foundry-cli",
+            "0x0000000000000000000000000000000000000000",
+            "--wasm",
+            "--verifier-url",
+            "https://example.com/api",
+        ]);
+        assert!(args.verifier.wasm_client().is_ok());
+    }
 }